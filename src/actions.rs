@@ -1,16 +1,16 @@
 //! actions the player can instruct the Warrior to take
 
+use crate::item::Item;
+
 /// Certain `Action`s are done one tile away, and must be done either
 /// while facing forwards or backwards.
-#[derive(Clone, Copy, Debug, PartialEq)]
-#[derive(Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
 pub enum Direction {
     #[default]
     Forward,
     Backward,
 }
 
-
 /// Certain [`Warrior`](crate::warrior::Warrior) methods correlate to
 /// an `Action`. Each turn only one action can be taken. If an action
 /// is not successful, then the turn is wasted!
@@ -26,6 +26,8 @@ pub enum Action {
     Rescue(Direction),
     /// rotate 180 degrees
     Pivot(Direction),
-    /// fire an arrow up to three tiles
+    /// fire an arrow as far as the bow's range
     Shoot(Direction),
+    /// use an item from the inventory
+    Use(Item),
 }
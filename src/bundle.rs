@@ -0,0 +1,196 @@
+//! contains bundles for sharing a warrior (and their solution) with others
+//!
+//! A bundle is a single file with everything needed to set up a copy of a
+//! player's game directory somewhere else, e.g. to review their `Player`
+//! with a colleague or mentor:
+//!
+//! ```sh
+//! # from the game directory
+//! rust-warrior export
+//! # from the colleague's rustwarrior directory's parent
+//! rust-warrior import bot-level-7.bundle.toml
+//! ```
+
+use std::{
+    fs, io,
+    path::{Component, Path, PathBuf},
+};
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{
+    index::ProfileIndex,
+    profile::{self, Profile},
+    replay::{REPLAY_PATH, Replay},
+    starter,
+};
+
+/// A file of the player's game directory.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct BundleFile {
+    /// The path of the file, relative to the game directory
+    pub path: String,
+    pub contents: String,
+}
+
+/// A player's code, profile, and latest attempt at a level, in one file.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Bundle {
+    /// The name of the player's warrior
+    pub name: String,
+    /// The level of the latest attempt (or else the warrior's level)
+    pub level: usize,
+    /// The score of the latest attempt, if there was one
+    pub score: Option<i32>,
+    /// The .profile, as it was saved (i.e. still signed)
+    pub profile: String,
+    /// Cargo.toml and everything in src/
+    pub files: Vec<BundleFile>,
+    /// The latest attempt at a level
+    pub replay: Option<Replay>,
+}
+
+impl Bundle {
+    /// Bundle up the game directory at `player_dir`.
+    pub fn export(player_dir: &Path) -> Result<Bundle, String> {
+        let profile_contents = fs::read_to_string(player_dir.join(".profile"))
+            .map_err(|e| format!("failed to read .profile: {}", e))?;
+        let profile = Profile::from_toml(&profile_contents).map_err(|e| e.to_string())?;
+
+        let mut files = vec![read_file(player_dir, Path::new("Cargo.toml"))?];
+        let mut src = Vec::new();
+        list_files(player_dir, Path::new("src"), &mut src)?;
+        src.sort();
+        for path in src {
+            files.push(read_file(player_dir, &path)?);
+        }
+
+        let replay_path = player_dir.join(REPLAY_PATH);
+        let replay = if replay_path.exists() {
+            Some(Replay::load(&replay_path)?)
+        } else {
+            None
+        };
+
+        Ok(Bundle {
+            name: profile.name.clone(),
+            level: replay.as_ref().map_or(profile.level, |replay| replay.level),
+            score: replay.as_ref().map(|replay| replay.score),
+            profile: profile_contents,
+            files,
+            replay,
+        })
+    }
+
+    /// Set up a new game directory for the bundled warrior inside the
+    /// `rustwarrior` directory at `directory` (which is created if it does
+    /// not exist yet). Returns the path of the game directory.
+    pub fn import(&self, directory: &Path) -> Result<PathBuf, String> {
+        let profile = Profile::from_toml(&self.profile).map_err(|e| e.to_string())?;
+        // the game directory must be a new directory right inside `directory`
+        let profile_dir = profile.directory();
+        let mut components = Path::new(&profile_dir).components();
+        if !matches!(
+            (components.next(), components.next()),
+            (Some(Component::Normal(_)), None)
+        ) {
+            return Err(format!(
+                "the bundle is for an invalid warrior `{}`",
+                profile.name
+            ));
+        }
+        let player_dir = directory.join(&profile_dir);
+        if player_dir.symlink_metadata().is_ok() {
+            return Err(format!("{} already exists", player_dir.display()));
+        }
+        // the bundle may have come from anywhere, so only write files within
+        // the game directory
+        for file in &self.files {
+            let path = Path::new(&file.path);
+            if !path
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)))
+            {
+                return Err(format!(
+                    "the bundle contains an invalid path `{}`",
+                    file.path
+                ));
+            }
+        }
+
+        let write_error = |e: io::Error| format!("failed to write {}: {}", player_dir.display(), e);
+        for file in &self.files {
+            let path = player_dir.join(&file.path);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).map_err(write_error)?;
+            }
+            fs::write(path, &file.contents).map_err(write_error)?;
+        }
+        fs::write(player_dir.join(".profile"), &self.profile).map_err(write_error)?;
+        starter::write_readme(&profile, profile.level, Some(&player_dir)).map_err(write_error)?;
+        if let Some(replay) = &self.replay {
+            replay.save(&player_dir.join(REPLAY_PATH))?;
+        }
+
+        let index_error = |e: io::Error| format!("failed to update the index: {}", e);
+        let mut index = ProfileIndex::load(directory).map_err(index_error)?;
+        index.add(&profile);
+        index.save(directory).map_err(index_error)?;
+
+        Ok(player_dir)
+    }
+
+    /// The file name a bundle is exported to by default, e.g.
+    /// `bot-level-7.bundle.toml`
+    pub fn file_name(&self) -> String {
+        format!(
+            "{}-level-{}.bundle.toml",
+            profile::slug(&self.name),
+            self.level
+        )
+    }
+
+    /// load a Bundle from a TOML file at `path`
+    pub fn load(path: &Path) -> Result<Bundle, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+        toml::from_str(&contents).map_err(|e| format!("failed to parse {}: {}", path.display(), e))
+    }
+
+    /// save the Bundle as a TOML file at `path`
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let contents =
+            toml::to_string(self).map_err(|e| format!("failed to serialize bundle: {}", e))?;
+        fs::write(path, contents).map_err(|e| format!("failed to write {}: {}", path.display(), e))
+    }
+}
+
+// read the file at `path` (relative to `player_dir`) into the bundle
+fn read_file(player_dir: &Path, path: &Path) -> Result<BundleFile, String> {
+    let contents = fs::read_to_string(player_dir.join(path))
+        .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+    let components: Vec<String> = path
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy().into_owned())
+        .collect();
+    Ok(BundleFile {
+        path: components.join("/"),
+        contents,
+    })
+}
+
+// every file in `dir` (relative to `player_dir`), recursively
+fn list_files(player_dir: &Path, dir: &Path, files: &mut Vec<PathBuf>) -> Result<(), String> {
+    let entries = fs::read_dir(player_dir.join(dir))
+        .map_err(|e| format!("failed to read {}: {}", dir.display(), e))?;
+    for entry in entries {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let path = dir.join(entry.file_name());
+        if entry.path().is_dir() {
+            list_files(player_dir, &path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
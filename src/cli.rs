@@ -0,0 +1,549 @@
+//! contains the command line interface of the `rust-warrior` binary
+//!
+//! Running `rust-warrior` without a command prompts for everything needed to
+//! set up a new game directory. Each prompt also has a flag, so that setting
+//! up (e.g. a whole classroom of) game directories can be scripted.
+
+use std::{collections::HashMap, env, fs, path::Path};
+
+use crate::{
+    bundle::Bundle,
+    difficulty::Difficulty,
+    floor::Floor,
+    generator::{self, DEFAULT_WIDTH, Generator},
+    index::{INDEX_PATH, IndexEntry, ProfileIndex},
+    profile::{LevelHistory, Profile, ProfileError},
+    replay::{REPLAY_PATH, Replay},
+    starter, ui,
+};
+
+const USAGE: &str = "Usage: rust-warrior [COMMAND]
+
+Without a command, you are prompted to set up a new game directory.
+
+Commands:
+  new --name <name> [--dir <dir>] [--tower <tower>] [--difficulty <difficulty>] [--hardcore]
+                      set up a new game directory without any prompts
+                      (difficulty is easy, normal, or hard; in hardcore mode
+                      a death sends the warrior back to Level 1)
+  profiles list [--dir <dir>]
+                      list the warriors set up in the rustwarrior directory
+  profiles switch <name> [--dir <dir>]
+                      make a warrior the current one
+  profiles clone <name> --name <new name> [--dir <dir>]
+                      set up a new warrior at the same level as another
+  profiles delete <name> [--dir <dir>] [--yes]
+                      delete a warrior's game directory
+  levels              list every level of the tower
+  show <level>        print the map and README of a level
+  practice [--seed <seed>] [--level <n>] [--width <n>] [--height <n>]
+           [--difficulty <difficulty>]
+                      print a practice floor for a warrior at a level (by
+                      default, the level and difficulty of the profile in the
+                      current directory)
+  reset [--level <n>] restart the profile in the current directory at a level
+  stats               print the history of the profile in the current
+                      directory, level by level (and any daily challenges)
+  upgrade             update the game directory in the current directory to
+                      this version of rust-warrior
+  export [--output <file>]
+                      bundle up the game directory in the current directory
+                      (code, profile, and latest attempt) to share it
+  import <file> [--dir <dir>]
+                      set up a game directory from a bundle
+  replay <file> [--delay <ms>]
+                      watch a recorded attempt at a level
+  help                print this message";
+
+// the towers a new game can climb
+const TOWERS: &[&str] = &["beginner"];
+
+// the name used when there is no profile to take it from
+const DEFAULT_PLAYER_NAME: &str = "Warrior";
+
+/// Run the command described by `args` (not including the binary's name).
+pub fn run(args: &[String]) -> Result<(), String> {
+    let Some((command, args)) = args.split_first() else {
+        return starter::generate().map_err(|e| e.to_string());
+    };
+    match command.as_str() {
+        "new" => new(args),
+        "profiles" => profiles(args),
+        "levels" => levels(args),
+        "show" => show(args),
+        "practice" => practice(args),
+        "reset" => reset(args),
+        "stats" => stats(args),
+        "upgrade" => upgrade(args),
+        "export" => export(args),
+        "import" => import(args),
+        "replay" => replay(args),
+        "help" | "--help" | "-h" => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        command => Err(format!("unknown command `{}`\n\n{}", command, USAGE)),
+    }
+}
+
+fn new(args: &[String]) -> Result<(), String> {
+    let (options, _) = parse(
+        args,
+        &["--name", "--dir", "--tower", "--difficulty"],
+        &["--hardcore"],
+        0,
+    )?;
+    let name = options
+        .get("--name")
+        .ok_or_else(|| String::from("`new` requires --name <name>"))?;
+    let tower = options.get("--tower").map_or(TOWERS[0], String::as_str);
+    if !TOWERS.contains(&tower) {
+        return Err(format!(
+            "unknown tower `{}` (available towers: {})",
+            tower,
+            TOWERS.join(", ")
+        ));
+    }
+    let mut profile = Profile::new(name.clone());
+    if let Some(difficulty) = options.get("--difficulty") {
+        profile.difficulty = difficulty.parse()?;
+    }
+    profile.hardcore = options.contains_key("--hardcore");
+    let player_dir =
+        starter::generate_in(profile, rustwarrior_dir(&options)).map_err(|e| e.to_string())?;
+    println!(
+        "Game files have been generated. See {}/README.md for instructions.",
+        player_dir.display()
+    );
+    Ok(())
+}
+
+fn profiles(args: &[String]) -> Result<(), String> {
+    let Some((command, args)) = args.split_first() else {
+        return Err(format!("`profiles` requires a command\n\n{}", USAGE));
+    };
+    match command.as_str() {
+        "list" => {
+            let (options, _) = parse(args, &["--dir"], &[], 0)?;
+            let dir = rustwarrior_dir(&options);
+            let index = load_index(dir)?;
+            if index.warriors.is_empty() {
+                println!("No warriors have been set up in {} yet.", dir.display());
+            }
+            for warrior in &index.warriors {
+                let marker = if index.current.as_ref() == Some(&warrior.directory) {
+                    "*"
+                } else {
+                    " "
+                };
+                let level = match Profile::load(&dir.join(&warrior.directory).join(".profile")) {
+                    Ok(profile) => {
+                        let title = if profile.maximus_oxidus {
+                            String::from("Maximus Oxidus")
+                        } else {
+                            format!("Level {}", profile.level)
+                        };
+                        match profile.unproven_levels().len() {
+                            0 => title,
+                            unproven => format!("{}, {} unproven", title, unproven),
+                        }
+                    }
+                    Err(e) => e.to_string(),
+                };
+                println!(
+                    "{} {} ({}) {}",
+                    marker,
+                    &warrior.name,
+                    level,
+                    dir.join(&warrior.directory).display()
+                );
+            }
+            Ok(())
+        }
+        "switch" => {
+            let (options, positional) = parse(args, &["--dir"], &[], 1)?;
+            let dir = rustwarrior_dir(&options);
+            let mut index = load_index(dir)?;
+            let warrior = find_warrior(&index, &positional[0])?;
+            index.current = Some(warrior.directory.clone());
+            save_index(&index, dir)?;
+            println!(
+                "{} is now the current warrior. Use `cd {}` to play.",
+                &warrior.name,
+                dir.join(&warrior.directory).display()
+            );
+            Ok(())
+        }
+        "clone" => {
+            let (options, positional) = parse(args, &["--dir", "--name"], &[], 1)?;
+            let dir = rustwarrior_dir(&options);
+            let name = options
+                .get("--name")
+                .ok_or_else(|| String::from("`profiles clone` requires --name <new name>"))?;
+            let index = load_index(dir)?;
+            let warrior = find_warrior(&index, &positional[0])?;
+            let source = Profile::load(&dir.join(&warrior.directory).join(".profile"))
+                .map_err(|e| e.to_string())?;
+            let player_dir =
+                starter::generate_clone(&source, name, dir).map_err(|e| e.to_string())?;
+            println!(
+                "{} starts out at Level {}, like {}. See {}/README.md for instructions.",
+                name,
+                source.level,
+                &source.name,
+                player_dir.display()
+            );
+            Ok(())
+        }
+        "delete" => {
+            let (options, positional) = parse(args, &["--dir"], &["--yes"], 1)?;
+            let dir = rustwarrior_dir(&options);
+            let mut index = load_index(dir)?;
+            let warrior = find_warrior(&index, &positional[0])?;
+            let player_dir = dir.join(&warrior.directory);
+            let message = format!(
+                "Delete {} and everything in {}?",
+                &warrior.name,
+                player_dir.display()
+            );
+            if !options.contains_key("--yes") && !ui::ask(&message) {
+                println!("{} was not deleted.", &warrior.name);
+                return Ok(());
+            }
+            fs::remove_dir_all(&player_dir).map_err(|e| e.to_string())?;
+            index.remove(&warrior.directory);
+            save_index(&index, dir)?;
+            println!("{} has been deleted.", &warrior.name);
+            Ok(())
+        }
+        command => Err(format!(
+            "unknown command `profiles {}`\n\n{}",
+            command, USAGE
+        )),
+    }
+}
+
+fn levels(args: &[String]) -> Result<(), String> {
+    parse(args, &[], &[], 0)?;
+    for level in 1..=Floor::count() {
+        let readme = starter::generate_readme(level, DEFAULT_PLAYER_NAME, Difficulty::Normal);
+        // the README's first paragraph sets the scene
+        let description: Vec<&str> = readme
+            .lines()
+            .skip(2)
+            .take_while(|line| !line.is_empty())
+            .collect();
+        println!("Level {}: {}", level, description.join(" "));
+    }
+    Ok(())
+}
+
+fn show(args: &[String]) -> Result<(), String> {
+    let (_, positional) = parse(args, &[], &[], 1)?;
+    let level = parse_level(&positional[0])?;
+    let (name, difficulty) = load_profile()
+        .map(|profile| (profile.name, profile.difficulty))
+        .unwrap_or_else(|_| (String::from(DEFAULT_PLAYER_NAME), Difficulty::Normal));
+    println!("{}", Floor::load(level).draw());
+    println!();
+    println!("{}", starter::generate_readme(level, &name, difficulty));
+    Ok(())
+}
+
+fn practice(args: &[String]) -> Result<(), String> {
+    let (options, _) = parse(
+        args,
+        &["--seed", "--level", "--width", "--height", "--difficulty"],
+        &[],
+        0,
+    )?;
+    let profile = load_profile().ok();
+    let seed = match options.get("--seed") {
+        Some(seed) => seed
+            .parse()
+            .map_err(|_| format!("`{}` is not a valid seed", seed))?,
+        None => generator::random_seed(),
+    };
+    let level = match (options.get("--level"), &profile) {
+        (Some(level), _) => parse_level(level)?,
+        (None, Some(profile)) if !profile.maximus_oxidus => profile.level,
+        _ => Floor::count(),
+    };
+    let difficulty = match (options.get("--difficulty"), &profile) {
+        (Some(difficulty), _) => difficulty.parse()?,
+        (None, Some(profile)) => profile.difficulty,
+        (None, None) => Difficulty::Normal,
+    };
+    let mut generator = Generator::new(seed, level).with_difficulty(difficulty);
+    if let Some(width) = options.get("--width") {
+        let width = width
+            .parse()
+            .map_err(|_| format!("`{}` is not a valid width", width))?;
+        generator = generator.with_width(width);
+    }
+    if let Some(height) = options.get("--height") {
+        let height = height
+            .parse()
+            .map_err(|_| format!("`{}` is not a valid height", height))?;
+        generator = generator.with_height(height);
+    }
+    let floor = generator.generate();
+    println!(
+        "Practice floor for Level {} ({}, seed {}):",
+        level, difficulty, seed
+    );
+    println!("{}", floor.draw());
+    for objective in &floor.objectives {
+        println!("Objective: {}", objective);
+    }
+    // the game builds practice floors at the default width, a single row tall
+    if generator.width == DEFAULT_WIDTH && generator.height == 1 {
+        println!();
+        println!(
+            "A warrior at Level {} on {} can play it from their game directory with `cargo run -- --practice {}`.",
+            level, difficulty, seed
+        );
+    }
+    Ok(())
+}
+
+fn reset(args: &[String]) -> Result<(), String> {
+    let (options, _) = parse(args, &["--level"], &[], 0)?;
+    let level = match options.get("--level") {
+        Some(level) => parse_level(level)?,
+        None => 1,
+    };
+    let mut profile = load_profile()?;
+    // a warrior can only be sent back to a level they have proven they reached
+    let top = profile.level.min(profile.proven_level());
+    if level > top {
+        return Err(format!(
+            "{} can only be reset to Levels 1 to {}",
+            &profile.name, top
+        ));
+    }
+    profile.level = level;
+    profile.maximus_oxidus = false;
+    starter::write_profile(&profile, None)
+        .map_err(|e| format!("failed to write .profile: {}", e))?;
+    starter::write_readme(&profile, level, None)
+        .map_err(|e| format!("failed to write README.md: {}", e))?;
+    println!(
+        "{} is back on Level {}. See (updated) README.md for instructions.",
+        &profile.name, level
+    );
+    Ok(())
+}
+
+fn stats(args: &[String]) -> Result<(), String> {
+    parse(args, &[], &[], 0)?;
+    let profile = load_profile()?;
+    let title = if profile.maximus_oxidus {
+        String::from("Maximus Oxidus")
+    } else {
+        format!("Level {}", profile.level)
+    };
+    println!("{} ({})", &profile.name, title);
+    let unproven = profile.unproven_levels();
+    if !unproven.is_empty() {
+        let levels: Vec<String> = unproven.iter().map(|level| level.to_string()).collect();
+        println!("Levels without proof of completion: {}", levels.join(", "));
+    }
+    if profile.history.is_empty() {
+        println!("No levels have been attempted yet.");
+    } else {
+        print_history(&profile.history);
+    }
+    if profile.hardcore || !profile.hardcore_history.is_empty() {
+        println!();
+        println!(
+            "Hardcore: best cleared Level {}, {} death(s)",
+            profile.hardcore_best, profile.hardcore_deaths
+        );
+        if !profile.hardcore_history.is_empty() {
+            print_history(&profile.hardcore_history);
+        }
+    }
+    if !profile.daily_history.is_empty() {
+        println!();
+        println!("Daily challenges:");
+        println!();
+        println!("{:<10} {:>8} {:>10}", "Date", "Attempts", "Best Score");
+        for history in &profile.daily_history {
+            let best = history.best_score.map(|score| score.to_string());
+            println!(
+                "{:<10} {:>8} {:>10}",
+                history.date,
+                history.attempts,
+                best.unwrap_or_else(|| String::from("-"))
+            );
+        }
+    }
+    Ok(())
+}
+
+// a table of every level's history
+fn print_history(histories: &[LevelHistory]) {
+    println!();
+    println!(
+        "{:<6} {:>8} {:>13} {:>10} {:>12} {:>7}",
+        "Level", "Attempts", "First Cleared", "Best Score", "Fewest Turns", "Most HP"
+    );
+    let or_dash = |value: Option<String>| value.unwrap_or_else(|| String::from("-"));
+    for history in histories {
+        println!(
+            "{:<6} {:>8} {:>13} {:>10} {:>12} {:>7}",
+            history.level,
+            history.attempts,
+            or_dash(history.first_cleared.clone()),
+            or_dash(history.best_score.map(|score| score.to_string())),
+            or_dash(history.fewest_turns.map(|turns| turns.to_string())),
+            or_dash(history.most_hp.map(|hp| hp.to_string())),
+        );
+    }
+}
+
+fn upgrade(args: &[String]) -> Result<(), String> {
+    parse(args, &[], &[], 0)?;
+    load_profile()?;
+    let profile = starter::upgrade(None).map_err(|e| e.to_string())?;
+    println!(
+        "{}'s game directory now uses rust-warrior {}. See (updated) README.md for Level {} instructions.",
+        &profile.name,
+        env!("CARGO_PKG_VERSION"),
+        profile.level
+    );
+    Ok(())
+}
+
+fn export(args: &[String]) -> Result<(), String> {
+    let (options, _) = parse(args, &["--output"], &[], 0)?;
+    load_profile()?;
+    let bundle = Bundle::export(Path::new("."))?;
+    let output = options
+        .get("--output")
+        .cloned()
+        .unwrap_or_else(|| bundle.file_name());
+    bundle.save(Path::new(&output))?;
+    println!(
+        "{}'s code and latest attempt at Level {} have been bundled up in {}.",
+        &bundle.name, bundle.level, output
+    );
+    Ok(())
+}
+
+fn import(args: &[String]) -> Result<(), String> {
+    let (options, positional) = parse(args, &["--dir"], &[], 1)?;
+    let dir = rustwarrior_dir(&options);
+    let bundle = Bundle::load(Path::new(&positional[0]))?;
+    fs::create_dir_all(dir).map_err(|e| format!("failed to create {}: {}", dir.display(), e))?;
+    let player_dir = bundle.import(dir)?;
+    let score = match bundle.score {
+        Some(score) => format!(" (scoring {})", score),
+        None => String::new(),
+    };
+    println!(
+        "{}'s attempt at Level {}{} has been set up in {}.",
+        &bundle.name,
+        bundle.level,
+        score,
+        player_dir.display()
+    );
+    if bundle.replay.is_some() {
+        println!(
+            "Use `rust-warrior replay {}` to watch it.",
+            player_dir.join(REPLAY_PATH).display()
+        );
+    }
+    Ok(())
+}
+
+fn replay(args: &[String]) -> Result<(), String> {
+    let (options, positional) = parse(args, &["--delay"], &[], 1)?;
+    let delay = match options.get("--delay") {
+        Some(delay) => delay
+            .parse()
+            .map_err(|_| format!("`{}` is not a valid delay", delay))?,
+        None => env::var("GAME_LOOP_DELAY")
+            .ok()
+            .and_then(|delay| delay.parse().ok())
+            .unwrap_or(500),
+    };
+    let replay = Replay::load(Path::new(&positional[0]))?;
+    replay.play(delay);
+    Ok(())
+}
+
+// split `args` into the values of the `known` options (with `flags` being
+// options without a value) and exactly `count` positional arguments
+fn parse(
+    args: &[String],
+    known: &[&str],
+    flags: &[&str],
+    count: usize,
+) -> Result<(HashMap<String, String>, Vec<String>), String> {
+    let mut options = HashMap::new();
+    let mut positional = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if flags.contains(&arg.as_str()) {
+            options.insert(arg.clone(), String::new());
+        } else if arg.starts_with("--") {
+            if !known.contains(&arg.as_str()) {
+                return Err(format!("unexpected option `{}`\n\n{}", arg, USAGE));
+            }
+            let value = args
+                .next()
+                .ok_or_else(|| format!("`{}` requires a value", arg))?;
+            options.insert(arg.clone(), value.clone());
+        } else {
+            positional.push(arg.clone());
+        }
+    }
+    if positional.len() != count {
+        return Err(format!("unexpected arguments\n\n{}", USAGE));
+    }
+    Ok((options, positional))
+}
+
+fn parse_level(level: &str) -> Result<usize, String> {
+    match level.parse() {
+        Ok(level) if Floor::exists(level) => Ok(level),
+        _ => Err(format!(
+            "`{}` is not a level (choose from 1 to {})",
+            level,
+            Floor::count()
+        )),
+    }
+}
+
+// the `rustwarrior` directory that the warriors are set up in
+fn rustwarrior_dir(options: &HashMap<String, String>) -> &Path {
+    Path::new(options.get("--dir").map_or("rustwarrior", String::as_str))
+}
+
+fn load_index(dir: &Path) -> Result<ProfileIndex, String> {
+    ProfileIndex::load(dir).map_err(|e| format!("failed to load {}: {}", dir.display(), e))
+}
+
+fn save_index(index: &ProfileIndex, dir: &Path) -> Result<(), String> {
+    index
+        .save(dir)
+        .map_err(|e| format!("failed to save {}: {}", dir.join(INDEX_PATH).display(), e))
+}
+
+fn find_warrior(index: &ProfileIndex, name: &str) -> Result<IndexEntry, String> {
+    index
+        .find(name)
+        .cloned()
+        .ok_or_else(|| format!("no warrior named `{}`", name))
+}
+
+fn load_profile() -> Result<Profile, String> {
+    Profile::load(Path::new(".profile")).map_err(|e| match e {
+        ProfileError::Missing => {
+            String::from("no .profile found, run this from your game directory")
+        }
+        e => e.to_string(),
+    })
+}
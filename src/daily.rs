@@ -0,0 +1,40 @@
+//! contains the daily challenge
+//!
+//! Every day there is a new practice floor (see
+//! [`generator`](crate::generator)) for warriors who have earned the title
+//! Maximus Oxidus. Its seed comes from the date alone, so everyone who takes
+//! on the challenge that day faces the same floor, without any server to ask.
+//! The day changes at the warrior's local midnight.
+//!
+//! The floor is the same for every warrior: it is always played with every
+//! ability and on Normal difficulty.
+
+use chrono::Local;
+
+use crate::{
+    floor::Floor,
+    generator::{DEFAULT_WIDTH, Generator},
+};
+
+/// The width of a daily challenge floor.
+pub const WIDTH: usize = DEFAULT_WIDTH + 2;
+
+/// Today's (local) date as YYYY-MM-DD.
+pub fn today() -> String {
+    Local::now().date_naive().to_string()
+}
+
+/// The seed of the daily challenge on `date`, a hash (64-bit FNV-1a) of the
+/// date.
+pub fn seed(date: &str) -> u64 {
+    date.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// The floor of the daily challenge on `date` (YYYY-MM-DD).
+pub fn floor(date: &str) -> Floor {
+    Generator::new(seed(date), Floor::count())
+        .with_width(WIDTH)
+        .generate()
+}
@@ -0,0 +1,109 @@
+//! contains the difficulty settings a player chooses when they start out
+
+use std::{fmt, str::FromStr};
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::unit::{Unit, UnitType};
+
+/// How tough the tower is. The difficulty scales the HP and ATK of enemies,
+/// the number of turns before the warrior is lost, the HP the warrior
+/// regains from resting, and the points earned for clearing a level.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    /// Every difficulty, from easiest to hardest
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+    /// The HP and ATK of enemies, as a percentage of their usual stats
+    pub fn enemy_percent(self) -> i32 {
+        match self {
+            Difficulty::Easy => 75,
+            Difficulty::Normal => 100,
+            Difficulty::Hard => 115,
+        }
+    }
+
+    /// The number of turns a warrior has to reach the stairs before they
+    /// are lost
+    pub fn turn_limit(self) -> usize {
+        match self {
+            Difficulty::Easy => 150,
+            Difficulty::Normal => 100,
+            Difficulty::Hard => 75,
+        }
+    }
+
+    /// The HP regained from resting, as a percentage of max HP
+    pub fn rest_percent(self) -> i32 {
+        match self {
+            Difficulty::Easy => 15,
+            Difficulty::Normal => 10,
+            Difficulty::Hard => 5,
+        }
+    }
+
+    /// The points earned for a level, as a percentage of its usual score
+    pub fn score_percent(self) -> i32 {
+        match self {
+            Difficulty::Easy => 50,
+            Difficulty::Normal => 100,
+            Difficulty::Hard => 150,
+        }
+    }
+
+    /// Scale the HP and ATK (including any ranged attack) of `unit` if it
+    /// is an enemy. Enemies keep at least 1 HP, and at least 1 ATK if they
+    /// had any.
+    pub fn scale(self, unit: &mut Unit) {
+        let enemy = matches!(
+            unit.unit_type,
+            UnitType::Archer | UnitType::Sludge | UnitType::ThickSludge | UnitType::Wizard
+        );
+        if !enemy {
+            return;
+        }
+        let scale = |value: i32| {
+            if value == 0 {
+                0
+            } else {
+                (value * self.enemy_percent() + 50).div_euclid(100).max(1)
+            }
+        };
+        let (current, max) = unit.hp;
+        unit.hp = (scale(current), scale(max));
+        unit.atk = scale(unit.atk);
+        if let Some(ranged) = &mut unit.ranged {
+            ranged.atk = scale(ranged.atk);
+        }
+    }
+}
+
+impl FromStr for Difficulty {
+    type Err = String;
+
+    /// Parse a difficulty by name, ignoring case (e.g. `hard`).
+    fn from_str(s: &str) -> Result<Difficulty, String> {
+        Difficulty::ALL
+            .into_iter()
+            .find(|difficulty| difficulty.to_string().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                format!(
+                    "unknown difficulty `{}` (choose from easy, normal, or hard)",
+                    s
+                )
+            })
+    }
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
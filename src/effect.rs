@@ -0,0 +1,32 @@
+//! contains types that represent status effects on units
+
+/// A lingering condition which changes how a unit behaves each turn.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Effect {
+    /// takes 1 damage per turn and cannot rest
+    Poisoned,
+    /// cannot act at all
+    Stunned,
+    /// can only act every other turn
+    Slowed,
+}
+
+/// The damage dealt by poison each turn.
+pub const POISON_ATK: i32 = 1;
+
+/// An [`Effect`](crate::effect::Effect) along with the number of `turns` it
+/// will last. Effects take hold at the start of the next turn, and are
+/// ticked by the [`effect_system`](crate::engine::systems::effect_system)
+/// once per turn. While the warrior is deciding what to do, `turns` is the
+/// number of turns the effect will last *after* the current one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StatusEffect {
+    pub effect: Effect,
+    pub turns: usize,
+}
+
+impl StatusEffect {
+    pub fn new(effect: Effect, turns: usize) -> StatusEffect {
+        StatusEffect { effect, turns }
+    }
+}
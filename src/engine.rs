@@ -15,59 +15,233 @@
 //!
 //! [specs]: https://github.com/slide-rs/specs
 
-use std::{env, thread, time};
+use std::{collections::HashSet, env, thread, time};
 
-use crate::{floor::Floor, unit::UnitType, Player};
+use crate::{
+    equipment::Equipment,
+    floor::{Floor, Tile},
+    player::PlayerGenerator,
+    unit::{Unit, UnitType},
+};
 
 #[cfg(feature = "ncurses")]
 pub mod curses;
+pub mod outcome;
+pub mod ranged;
 pub mod systems;
 pub mod world;
 
-use systems::{player_system, shooter_system, sludge_system, ui_system};
-use world::World;
+use outcome::{MatchSummary, Outcome};
+use systems::{
+    effect_system, golem_system, hazard_system, player_system, shooter_system, sludge_system,
+    ui_system,
+};
+use world::{Combatant, World};
 
 const DEFAULT_GAME_LOOP_DELAY: u64 = 1000;
 
-/// The entry point for the engine, called by [`Game`](crate::game::Game)
+/// The entry point for the engine, called by [`Game`](crate::game::Game).
+/// Plays the level until the warrior reaches the stairs, dies, or gets
+/// lost, and then returns an [`Outcome`](crate::engine::outcome::Outcome).
+/// A floor without exactly one warrior is not played at all, and the
+/// outcome's `failure` says why.
 pub fn start(
     player_name: String,
     warrior_level: usize,
     floor: Floor,
-    player_generator: fn() -> Box<dyn Player + Send + Sync>,
-) -> Result<(), String> {
-    let player = player_generator();
+    player_generator: PlayerGenerator,
+) -> Outcome {
+    start_with_golem(player_name, warrior_level, floor, player_generator, None)
+}
 
-    #[cfg(feature = "ncurses")]
-    let mut c = curses::Curses::new();
+/// Like [`start`](crate::engine::start), but a second `Player` controls a
+/// Golem companion. The golem is either placed by the floor itself, or
+/// summoned next to the warrior (behind, if there is room).
+pub fn start_with_golem(
+    player_name: String,
+    warrior_level: usize,
+    floor: Floor,
+    player_generator: PlayerGenerator,
+    golem_generator: Option<PlayerGenerator>,
+) -> Outcome {
+    let players = vec![(player_name, player_generator)];
+    let mut world = match setup(warrior_level, floor, players, Mode::CoOp) {
+        Ok(world) => world,
+        Err(failure) => {
+            return Outcome {
+                failure: Some(failure),
+                ..Default::default()
+            };
+        }
+    };
+
+    if let Some(golem_generator) = golem_generator {
+        if world.golem.is_none() {
+            world.golem = summon_golem(&world);
+        }
+        world.golem_player = Some(golem_generator());
+    }
 
-    #[cfg(not(feature = "ncurses"))]
-    println!("{}", floor.draw());
+    let failure = run(&mut world, Mode::CoOp, false);
+    world.outcome(failure)
+}
 
-    let mut step = 0;
+/// Like [`start`](crate::engine::start), but nothing is printed and there
+/// is no delay between turns. Used to run many levels quickly, e.g. by a
+/// [`tournament`](crate::tournament).
+pub fn start_headless(
+    player_name: String,
+    warrior_level: usize,
+    floor: Floor,
+    player_generator: PlayerGenerator,
+) -> Outcome {
+    let players = vec![(player_name, player_generator)];
+    let mut world = match setup(warrior_level, floor, players, Mode::CoOp) {
+        Ok(world) => world,
+        Err(failure) => {
+            return Outcome {
+                failure: Some(failure),
+                ..Default::default()
+            };
+        }
+    };
+    let failure = run(&mut world, Mode::CoOp, true);
+    world.outcome(failure)
+}
+
+/// Plays a floor with several warriors working together, each controlled by
+/// one of the `players` (in the order the warriors appear in the floor's
+/// `units`). The level is only complete once every warrior has reached the
+/// stairs, and it is lost if any of them dies.
+///
+/// Returns an error if the floor does not have a warrior for every player
+/// (see [`Floor::with_party`](crate::floor::Floor::with_party)).
+pub fn start_coop(
+    warrior_level: usize,
+    floor: Floor,
+    players: Vec<(String, PlayerGenerator)>,
+) -> Result<Outcome, String> {
+    let mut world = setup(warrior_level, floor, players, Mode::CoOp)?;
+    let failure = run(&mut world, Mode::CoOp, false);
+    Ok(world.outcome(failure))
+}
+
+/// Pits the warriors of two players against each other on a symmetric floor
+/// (such as [`Floor::arena`](crate::floor::Floor::arena)). The match is won
+/// by the last warrior standing, and is a draw if both are still standing
+/// after the floor's turn limit (100 turns, unless the floor has another
+/// [`Difficulty`](crate::difficulty::Difficulty)).
+///
+/// Returns an error if the floor is not symmetric, or does not have exactly
+/// two warriors.
+pub fn start_versus(
+    warrior_level: usize,
+    floor: Floor,
+    players: [(String, PlayerGenerator); 2],
+) -> Result<MatchSummary, String> {
+    if !floor.is_symmetric() {
+        return Err(String::from(
+            "a versus match must be played on a symmetric floor",
+        ));
+    }
+    let mut world = setup(warrior_level, floor, players.to_vec(), Mode::Versus)?;
+    run(&mut world, Mode::Versus, false);
+    Ok(world.match_summary())
+}
 
-    let mut warrior = None;
+// in co-op every warrior must reach the stairs, in versus every warrior is on
+// a team of their own
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum Mode {
+    CoOp,
+    Versus,
+}
+
+// create the world, handing the floor's warriors to the players in order
+// (which fails unless there is exactly one warrior for every player)
+pub(crate) fn setup(
+    warrior_level: usize,
+    floor: Floor,
+    players: Vec<(String, PlayerGenerator)>,
+    mode: Mode,
+) -> Result<World, String> {
+    let mut warriors = Vec::new();
+    let mut golem = None;
     let mut other_units = Vec::new();
     for unit in &floor.units {
         match unit.unit_type {
             UnitType::Warrior => {
-                warrior = Some(unit.clone());
+                // the first warrior keeps any equipment they brought along
+                // (e.g. from the floor below in a gauntlet)
+                let mut warrior = unit.clone();
+                let equipment = match floor.equipment {
+                    Some(equipment) if warriors.is_empty() => equipment,
+                    _ => Equipment::for_level(warrior_level),
+                };
+                warrior.equip(equipment);
+                warriors.push(warrior);
+            }
+            UnitType::Golem => {
+                golem = Some(unit.clone());
             }
             _ => {
                 other_units.push(unit.clone());
             }
         }
     }
-    let warrior = warrior.unwrap();
+    if warriors.len() != players.len() {
+        return Err(format!(
+            "the floor has {} warrior(s) for {} player(s)",
+            warriors.len(),
+            players.len()
+        ));
+    }
 
+    let mut combatants = Vec::new();
+    for (team, (warrior, (name, player_generator))) in warriors.into_iter().zip(players).enumerate()
+    {
+        let team = match mode {
+            Mode::CoOp => 0,
+            Mode::Versus => team,
+        };
+        combatants.push(Combatant::new(name, player_generator(), warrior, team));
+    }
+
+    let first = combatants.remove(0);
     let mut world = World::new(
-        player_name,
+        first.name,
         warrior_level,
         floor,
-        player,
-        warrior,
+        first.player,
+        first.unit,
         other_units,
     );
+    world.warriors.append(&mut combatants);
+    world.golem = golem;
+
+    Ok(world)
+}
+
+// play turns until the level (or match) is over, returning why it was lost;
+// a `headless` world is neither drawn nor slowed down
+fn run(world: &mut World, mode: Mode, headless: bool) -> Option<String> {
+    #[cfg(feature = "ncurses")]
+    let mut c = if headless {
+        None
+    } else {
+        Some(curses::Curses::new())
+    };
+
+    if !headless {
+        let floor = systems::ui::update_floor(world).draw();
+
+        #[cfg(not(feature = "ncurses"))]
+        println!("{}", floor);
+
+        world.frames.push(vec![floor]);
+    }
+
+    let mut step = 0;
 
     let override_delay = env::var("GAME_LOOP_DELAY")
         .ok()
@@ -76,39 +250,81 @@ pub fn start(
     loop {
         step += 1;
 
-        if step > 100 {
-            return Err(format!(
-                "{} seems to have gotten lost...",
-                &world.player_name
-            ));
+        if step > world.floor.difficulty.turn_limit() {
+            let lost = world.warriors.iter().find(|warrior| warrior.is_active());
+            let name = lost.map_or("", |warrior| &warrior.name);
+            return Some(format!("{} seems to have gotten lost...", name));
         }
 
-        let (current, _) = world.warrior.hp;
-        if current == 0 {
-            return Err(format!("{} died!", &world.player_name));
+        match mode {
+            Mode::CoOp => {
+                if let Some(dead) = world.warriors.iter().find(|warrior| {
+                    let (current, _) = warrior.unit.hp;
+                    current == 0
+                }) {
+                    return Some(format!("{} died!", &dead.name));
+                }
+                for warrior in world.warriors.iter_mut() {
+                    if warrior.unit.position == world.floor.stairs && world.floor.stairs_open() {
+                        warrior.escaped = true;
+                    }
+                }
+                if world.warriors.iter().all(|warrior| warrior.escaped) {
+                    return None;
+                }
+            }
+            Mode::Versus => {
+                let standing: HashSet<usize> = world
+                    .warriors
+                    .iter()
+                    .filter(|warrior| warrior.is_active())
+                    .map(|warrior| warrior.team)
+                    .collect();
+                if standing.len() <= 1 {
+                    return None;
+                }
+            }
         }
-        if world.warrior.position == world.floor.stairs {
-            return Ok(());
+
+        world.turns += 1;
+        for warrior in world.warriors.iter_mut() {
+            warrior.previous_position = warrior.unit.position;
         }
 
         let mut events = Vec::new();
 
-        let mut player_events = player_system(&mut world);
+        let mut effect_events = effect_system(world);
+        events.append(&mut effect_events);
+
+        let mut player_events = player_system(world);
         events.append(&mut player_events);
 
-        let mut sludge_events = sludge_system(&mut world);
+        let mut golem_events = golem_system(world);
+        events.append(&mut golem_events);
+
+        let mut hazard_events = hazard_system(world);
+        events.append(&mut hazard_events);
+
+        let mut sludge_events = sludge_system(world);
         events.append(&mut sludge_events);
 
-        let mut shooter_events = shooter_system(&mut world);
+        let mut shooter_events = shooter_system(world);
         events.append(&mut shooter_events);
 
+        if headless {
+            continue;
+        }
+
         let num_events = events.len() as u64;
+        world.frames.push(systems::ui::frame(world, events.clone()));
 
         #[cfg(feature = "ncurses")]
-        ui_system(&world, events, &mut c);
+        if let Some(c) = c.as_mut() {
+            ui_system(world, events, c);
+        }
 
         #[cfg(not(feature = "ncurses"))]
-        ui_system(&world, events);
+        ui_system(world, events);
 
         let delay = match override_delay {
             Some(delay) => delay,
@@ -117,3 +333,12 @@ pub fn start(
         thread::sleep(time::Duration::from_millis(delay));
     }
 }
+
+// an empty tile next to the (first) warrior, preferring the one behind
+fn summon_golem(world: &World) -> Option<Unit> {
+    let (wx, wy) = world.warriors[0].unit.position;
+    [(wx - 1, wy), (wx + 1, wy)]
+        .into_iter()
+        .find(|&position| world.tile(position) == Tile::Empty)
+        .map(Unit::golem)
+}
@@ -0,0 +1,111 @@
+use std::fmt;
+
+use crate::{difficulty::Difficulty, equipment::Equipment, item::Item, objective::Objective};
+
+/// points earned for each enemy defeated
+pub const ENEMY_POINTS: i32 = 10;
+/// points earned for each captive rescued
+pub const CAPTIVE_POINTS: i32 = 20;
+/// a level cleared in fewer than this many turns earns a point per turn spared
+pub const TIME_BONUS_TURNS: usize = 50;
+
+/// A summary of how a level went, returned by
+/// [`engine::start`](crate::engine::start).
+#[derive(Clone, Debug, Default)]
+pub struct Outcome {
+    /// `None` if the warrior reached the stairs, otherwise the reason why
+    /// they did not (e.g. they died)
+    pub failure: Option<String>,
+    /// the number of turns that were played
+    pub turns: usize,
+    /// the (first) warrior's current/max HP at the end of the level
+    pub hp: (i32, i32),
+    /// whether a warrior died
+    pub died: bool,
+    /// the (first) warrior's inventory at the end of the level
+    pub inventory: Vec<Item>,
+    /// the (first) warrior's equipment at the end of the level, with
+    /// whatever arrows are left in the quiver
+    pub equipment: Option<Equipment>,
+    /// the total damage the warriors took
+    pub damage_taken: i32,
+    pub captives_rescued: usize,
+    pub captives_lost: usize,
+    pub captives_remaining: usize,
+    pub enemies_defeated: usize,
+    pub enemies_remaining: usize,
+    /// each of the floor's objectives and whether it was met
+    pub objectives: Vec<(Objective, bool)>,
+    /// the difficulty the level was played at
+    pub difficulty: Difficulty,
+    /// what was drawn on each turn, which can be saved as a
+    /// [`Replay`](crate::replay::Replay) (empty when played headlessly)
+    pub frames: Vec<Vec<String>>,
+}
+
+impl Outcome {
+    /// Returns `true` if the warrior reached the stairs and met every
+    /// objective.
+    pub fn is_success(&self) -> bool {
+        self.failure.is_none() && self.objectives.iter().all(|(_, met)| *met)
+    }
+
+    /// The points earned for the level, which are only awarded if it was
+    /// completed successfully. Points are earned for every enemy defeated and
+    /// captive rescued, plus a time bonus for finishing quickly. Clearing the
+    /// floor entirely (no enemies or captives left behind) earns an extra 20%.
+    /// Finally, the score is scaled by the
+    /// [`Difficulty`](crate::difficulty::Difficulty).
+    pub fn score(&self) -> i32 {
+        if !self.is_success() {
+            return 0;
+        }
+        let time_bonus = TIME_BONUS_TURNS.saturating_sub(self.turns) as i32;
+        let score = self.enemies_defeated as i32 * ENEMY_POINTS
+            + self.captives_rescued as i32 * CAPTIVE_POINTS
+            + time_bonus;
+        let score = if self.enemies_remaining == 0 && self.captives_remaining == 0 {
+            score + score / 5
+        } else {
+            score
+        };
+        score * self.difficulty.score_percent() / 100
+    }
+
+    /// Evaluate each of `objectives` against this outcome.
+    pub fn evaluate(&mut self, objectives: &[Objective]) {
+        self.objectives = objectives
+            .iter()
+            .map(|objective| (*objective, objective.is_met(self)))
+            .collect();
+    }
+}
+
+/// A summary of a versus match, returned by
+/// [`engine::start_versus`](crate::engine::start_versus).
+#[derive(Clone, Debug, Default)]
+pub struct MatchSummary {
+    /// the name of the last warrior standing, or `None` if it was a draw
+    pub winner: Option<String>,
+    /// the number of turns that were played
+    pub turns: usize,
+    /// the name, current/max HP, and damage taken of each warrior
+    pub warriors: Vec<(String, (i32, i32), i32)>,
+}
+
+impl fmt::Display for MatchSummary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Match summary ({} turns)", self.turns)?;
+        for (name, (current, max), damage_taken) in &self.warriors {
+            writeln!(
+                f,
+                "  {}: {}/{} HP, took {} damage",
+                name, current, max, damage_taken
+            )?;
+        }
+        match &self.winner {
+            Some(winner) => write!(f, "{} wins!", winner),
+            None => write!(f, "The match is a draw."),
+        }
+    }
+}
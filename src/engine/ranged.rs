@@ -0,0 +1,132 @@
+//! contains the line of sight and range rules shared by ranged attackers
+//!
+//! Archers, wizards and the warrior's bow all attack in a straight line. An
+//! attack reaches a target only if it is within the attacker's `range` and
+//! nothing (another unit, a wall, or a locked door) stands in between. Some attacks also lose
+//! damage the further they travel, which is described by `falloff`.
+
+use std::cmp;
+
+use crate::{engine::world::World, floor::Tile};
+
+/// The ranged attack of a unit.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ranged {
+    /// the damage dealt to an adjacent target
+    pub atk: i32,
+    /// the furthest distance (in tiles) the attack can reach
+    pub range: i32,
+    /// the damage lost for every tile travelled beyond the first
+    pub falloff: i32,
+}
+
+impl Ranged {
+    /// Create a ranged attack without any damage falloff.
+    pub fn new(atk: i32, range: i32) -> Ranged {
+        Ranged {
+            atk,
+            range,
+            falloff: 0,
+        }
+    }
+
+    /// Returns `true` if a target `distance` tiles away can be reached.
+    pub fn reaches(&self, distance: i32) -> bool {
+        0 < distance && distance <= self.range
+    }
+
+    /// The damage dealt to a target `distance` tiles away.
+    pub fn damage(&self, distance: i32) -> i32 {
+        cmp::max(self.atk - self.falloff * (distance - 1), 0)
+    }
+}
+
+/// The number of tiles between `from` and `to` if they share a row or a
+/// column, otherwise `None` since attacks only travel in straight lines.
+pub fn distance(from: (i32, i32), to: (i32, i32)) -> Option<i32> {
+    let (fx, fy) = from;
+    let (tx, ty) = to;
+    if fy == ty {
+        Some((tx - fx).abs())
+    } else if fx == tx {
+        Some((ty - fy).abs())
+    } else {
+        None
+    }
+}
+
+/// Returns `true` if nothing blocks a straight line between `from` and `to`.
+/// Any unit standing on a tile in between blocks the line, as does any
+/// tile which is outside of the floor (a wall) or a locked door.
+pub fn line_of_sight(world: &World, from: (i32, i32), to: (i32, i32)) -> bool {
+    if distance(from, to).is_none() {
+        return false;
+    }
+    between(from, to).all(|position| !blocks_sight(world.tile(position)))
+}
+
+/// The damage a `ranged` attack from `from` deals to a target at `to`, or
+/// `None` if the target is out of range or out of sight.
+pub fn hit(world: &World, ranged: &Ranged, from: (i32, i32), to: (i32, i32)) -> Option<i32> {
+    let distance = distance(from, to)?;
+    if ranged.reaches(distance) && line_of_sight(world, from, to) {
+        Some(ranged.damage(distance))
+    } else {
+        None
+    }
+}
+
+/// The tiles seen from `from` when looking `range` tiles along `step`,
+/// paired with their positions. Scanning stops at the edge of the floor,
+/// and nothing beyond a locked door can be seen.
+pub fn scan(
+    world: &World,
+    from: (i32, i32),
+    step: (i32, i32),
+    range: i32,
+) -> Vec<((i32, i32), Tile)> {
+    let (x, y) = from;
+    let (dx, dy) = step;
+    let mut tiles = Vec::new();
+    for i in 1..=range {
+        let position = (x + dx * i, y + dy * i);
+        let tile = world.tile(position);
+        if tile == Tile::Wall {
+            break;
+        }
+        tiles.push((position, tile));
+        if tile == Tile::Door {
+            break;
+        }
+    }
+    tiles
+}
+
+/// The position of the first unit along `step` from `from`, if one is
+/// within `range` tiles.
+pub fn first_target(
+    world: &World,
+    from: (i32, i32),
+    step: (i32, i32),
+    range: i32,
+) -> Option<(i32, i32)> {
+    scan(world, from, step, range)
+        .into_iter()
+        .find(|(_, tile)| matches!(tile, Tile::Unit(_)))
+        .map(|(position, _)| position)
+}
+
+// units, walls and locked doors all block arrows (and wands)
+fn blocks_sight(tile: Tile) -> bool {
+    matches!(tile, Tile::Wall | Tile::Door | Tile::Unit(_))
+}
+
+// the positions strictly between `from` and `to` (which share a row or column)
+fn between(from: (i32, i32), to: (i32, i32)) -> impl Iterator<Item = (i32, i32)> {
+    let (fx, fy) = from;
+    let (tx, ty) = to;
+    let dx = (tx - fx).signum();
+    let dy = (ty - fy).signum();
+    let steps = cmp::max((tx - fx).abs(), (ty - fy).abs());
+    (1..steps).map(move |i| (fx + dx * i, fy + dy * i))
+}
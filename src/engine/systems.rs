@@ -1,9 +1,21 @@
 //! Game engine systems
 //!
+//! At the start of each turn, the
+//! [`effect_system`](crate::engine::systems::effect_system) ticks any status
+//! effects (such as poison).
+//!
 //! The [`player_system`](crate::engine::systems::player_system) function allows
 //! the player to control their [`Warrior`](crate::warrior::Warrior) and
 //! responds to their chosen action each turn.
 //!
+//! If the warrior has a golem companion, then the
+//! [`golem_system`](crate::engine::systems::golem_system) lets a second
+//! player control it.
+//!
+//! If a level contains hazards, then the
+//! [`hazard_system`](crate::engine::systems::hazard_system) springs any trap
+//! the warrior walks onto.
+//!
 //! If a level contains sludges, then the
 //! [`sludge_system`](crate::engine::systems::sludge_system) performs sludge
 //! attacks any time a sludge is within range of the player.
@@ -16,11 +28,17 @@
 //! overhead map of the floor and any units still alive after each turn takes
 //! place.
 
+pub mod effect;
+pub mod golem;
+pub mod hazard;
 pub mod player;
 pub mod shooter;
 pub mod sludge;
 pub mod ui;
 
+pub use effect::effect_system;
+pub use golem::golem_system;
+pub use hazard::hazard_system;
 pub use player::player_system;
 pub use shooter::shooter_system;
 pub use sludge::sludge_system;
@@ -0,0 +1,83 @@
+//! contains system for status effects
+
+use crate::{
+    effect::{Effect, POISON_ATK, StatusEffect},
+    engine::world::World,
+};
+
+/// This system ticks the [`StatusEffect`](crate::effect::StatusEffect)s of
+/// every [`Warrior`](crate::warrior::Warrior) and every other unit at the start
+/// of each turn. Any effect which ran its course last turn wears off, poison
+/// deals its damage, and every remaining effect counts down by one turn.
+pub fn effect_system(world: &mut World) -> Vec<String> {
+    let mut events = Vec::new();
+
+    for w in 0..world.warriors.len() {
+        if !world.warriors[w].is_active() {
+            continue;
+        }
+        let name = world.warriors[w].name.clone();
+        for effect in expire(&mut world.warriors[w].unit.effects) {
+            events.push(format!(
+                "{warrior} is no longer {effect:?}",
+                warrior = &name,
+                effect = effect
+            ));
+        }
+        if world.warriors[w].unit.has_effect(Effect::Poisoned) {
+            events.push(format!("{warrior} suffers from poison", warrior = &name));
+            // poison works from within, so armor is no help against it
+            let damage = world.hurt_warrior(w, POISON_ATK);
+            let (remaining, _) = world.warriors[w].unit.hp;
+            events.push(format!(
+                "{warrior} takes {damage} damage, {remaining} HP left",
+                warrior = &name,
+                damage = damage,
+                remaining = remaining
+            ));
+        }
+        tick(&mut world.warriors[w].unit.effects);
+    }
+
+    let mut i = 0;
+    while i < world.other_units.len() {
+        let unit = &mut world.other_units[i];
+        expire(&mut unit.effects);
+        if unit.has_effect(Effect::Poisoned) {
+            unit.lose_hp(POISON_ATK);
+            let (remaining, _) = unit.hp;
+            events.push(format!(
+                "{unit:?} suffers from poison, {remaining} HP left",
+                unit = unit.unit_type,
+                remaining = remaining
+            ));
+            if remaining == 0 {
+                events.push(format!("{:?} is dead!", unit.unit_type));
+                world.defeat_unit(i);
+                continue;
+            }
+        }
+        tick(&mut unit.effects);
+        i += 1;
+    }
+
+    events
+}
+
+// removes the effects which have run their course, returning them
+fn expire(effects: &mut Vec<StatusEffect>) -> Vec<Effect> {
+    let expired = effects
+        .iter()
+        .filter(|status| status.turns == 0)
+        .map(|status| status.effect)
+        .collect();
+    effects.retain(|status| status.turns > 0);
+    expired
+}
+
+// counts down each effect by one turn
+fn tick(effects: &mut [StatusEffect]) {
+    for status in effects.iter_mut() {
+        status.turns -= 1;
+    }
+}
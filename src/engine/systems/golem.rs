@@ -0,0 +1,106 @@
+//! contains system for the golem companion
+
+use std::cmp;
+
+use crate::{
+    Warrior,
+    actions::{Action, Direction},
+    engine::world::World,
+    floor::Tile,
+};
+
+/// This system lets a second [`Player`](crate::player::Player) control the
+/// Golem companion, if the level has one. The `play_turn` method is called
+/// with a restricted view (see
+/// [`Warrior::golem`](crate::warrior::Warrior::golem)), and the golem may
+/// walk, attack, or pivot.
+pub fn golem_system(world: &mut World) -> Vec<String> {
+    let mut events = Vec::new();
+
+    let golem = match &world.golem {
+        Some(golem) => golem.clone(),
+        None => return events,
+    };
+    let (gx, gy) = golem.position;
+    let (health, _) = golem.hp;
+    let facing = golem.facing.unwrap_or_default();
+
+    let (ahead, behind) = world.view(golem.position, facing, 1, false);
+    let view = Warrior::golem(ahead, behind, health, facing).with_effects(golem.effects.clone());
+
+    match world.golem_player.as_mut() {
+        Some(player) => player.play_turn(&view),
+        None => return events,
+    }
+
+    if let Some(action) = view.action() {
+        match action {
+            Action::Walk(direction) => {
+                let target_x = if facing == direction { gx + 1 } else { gx - 1 };
+                match world.tile((target_x, gy)) {
+                    Tile::Unit(unit_type) => {
+                        events.push(format!("Golem bumps into {:?}", unit_type));
+                    }
+                    Tile::Door | Tile::Wall => {
+                        events.push(String::from("Golem bumps into a wall"));
+                    }
+                    _ => {
+                        events.push(format!("Golem walks {:?}", direction));
+                        if let Some(golem) = world.golem.as_mut() {
+                            golem.position = (target_x, gy);
+                        }
+                    }
+                }
+            }
+            Action::Attack(direction) => {
+                let target_x = if facing == direction { gx + 1 } else { gx - 1 };
+                match world.unit_index_at((target_x, gy)) {
+                    Some(i) => {
+                        let enemy = &mut world.other_units[i];
+                        let atk = match direction {
+                            Direction::Forward => golem.atk,
+                            Direction::Backward => (golem.atk as f32 / 2.0).ceil() as i32,
+                        };
+                        let (current, max) = enemy.hp;
+                        let remaining = cmp::max(current - atk, 0);
+                        events.push(format!(
+                            "Golem attacks {direction:?} and hits {enemy:?}",
+                            direction = direction,
+                            enemy = enemy.unit_type
+                        ));
+                        events.push(format!(
+                            "{enemy:?} takes {atk} damage, {remaining} HP left",
+                            enemy = enemy.unit_type,
+                            atk = atk,
+                            remaining = remaining
+                        ));
+                        enemy.hp = (remaining, max);
+
+                        if remaining == 0 {
+                            events.push(format!("{:?} is dead!", enemy.unit_type));
+                            world.defeat_unit(i);
+                        }
+                    }
+                    None => {
+                        events.push(format!("Golem attacks {:?} and hits nothing", direction));
+                    }
+                }
+            }
+            Action::Pivot(direction) => {
+                events.push(format!("Golem pivots to face {:?}", direction));
+                if let Some(golem) = world.golem.as_mut() {
+                    golem.facing = Some(direction);
+                }
+            }
+            action => {
+                events.push(format!("Golem is unable to {:?}", action));
+            }
+        }
+    }
+
+    for warning in view.warnings() {
+        events.push(warning);
+    }
+
+    events
+}
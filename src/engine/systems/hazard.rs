@@ -0,0 +1,70 @@
+//! contains system for traps and hazardous tiles
+
+use crate::{
+    effect::{Effect, StatusEffect},
+    engine::world::World,
+    hazard::Hazard,
+};
+
+const SPIKES_ATK: i32 = 4;
+const SPIKES_SLOW_TURNS: usize = 2;
+const POISON_TURNS: usize = 3;
+const ARROW_TRAP_ATK: i32 = 3;
+
+/// This system springs any [`Hazard`](crate::hazard::Hazard) that a
+/// [`Warrior`](crate::warrior::Warrior) has just walked onto.
+pub fn hazard_system(world: &mut World) -> Vec<String> {
+    let mut events = Vec::new();
+
+    for w in 0..world.warriors.len() {
+        let warrior = &world.warriors[w];
+        let position = warrior.unit.position;
+        let hazard = if warrior.is_active() && position != warrior.previous_position {
+            world.floor.hazard(position)
+        } else {
+            None
+        };
+        let name = warrior.name.clone();
+
+        match hazard {
+            Some(Hazard::Spikes) => {
+                events.push(format!("{warrior} steps on spikes", warrior = &name));
+                events.push(damage_event(world, w, SPIKES_ATK));
+                world.warriors[w]
+                    .unit
+                    .afflict(StatusEffect::new(Effect::Slowed, SPIKES_SLOW_TURNS));
+            }
+            Some(Hazard::PoisonPool) => {
+                events.push(format!(
+                    "{warrior} wades into a poison pool and is poisoned",
+                    warrior = &name
+                ));
+                world.warriors[w]
+                    .unit
+                    .afflict(StatusEffect::new(Effect::Poisoned, POISON_TURNS));
+            }
+            Some(Hazard::PressurePlate) => {
+                events.push(format!(
+                    "{warrior} steps on a pressure plate and an arrow shoots out of the wall",
+                    warrior = &name
+                ));
+                events.push(damage_event(world, w, ARROW_TRAP_ATK));
+            }
+            None => {}
+        }
+    }
+
+    events
+}
+
+fn damage_event(world: &mut World, w: usize, atk: i32) -> String {
+    let damage = world.damage_warrior(w, atk);
+    let warrior = &world.warriors[w];
+    let (remaining, _) = warrior.unit.hp;
+    format!(
+        "{warrior} takes {damage} damage, {remaining} HP left",
+        warrior = &warrior.name,
+        damage = damage,
+        remaining = remaining
+    )
+}
@@ -3,74 +3,71 @@
 use std::cmp;
 
 use crate::{
+    Warrior,
     actions::{Action, Direction},
-    engine::world::World,
+    effect::Effect,
+    engine::{
+        ranged::{self, Ranged},
+        world::World,
+    },
+    equipment::Bow,
     floor::Tile,
+    item::{ARROW_BUNDLE_AMMO, HEALTH_POTION_HP, Item},
     unit::UnitType,
-    Warrior,
 };
 
 /// This system defines all of the interactions that are possible for the
 /// player-controlled [`Warrior`](crate::warrior::Warrior). The `play_turn`
 /// method is called on [`Player`](crate::player::Player), passing a `&mut`
-/// warrior whose actions must be specified.
+/// warrior whose actions must be specified. When there are several
+/// warriors on the floor, each of their players takes a turn in order.
 pub fn player_system(world: &mut World) -> Vec<String> {
     let mut events = Vec::new();
 
-    let (wx, wy) = world.warrior.position;
-    let (health, _) = world.warrior.hp;
-    let facing = world.warrior.facing.unwrap();
-
-    // NOTE: with the bow this range is 3 spaces
-    // TODO: conditionally determine warrior's range
-    let x_min = cmp::max(wx - 3, 0);
-    let x_max = cmp::min(wx + 3, world.floor.width as i32 - 1);
-
-    // include the x value with the tile enum variants
-    let west: Vec<(i32, Tile)> = (x_min..wx)
-        .rev()
-        .map(|i| {
-            let unit = world.other_units.iter_mut().find(|unit| {
-                let (x, _) = unit.position;
-                x == i
-            });
-            match unit {
-                Some(unit) => (i, Tile::Unit(unit.unit_type)),
-                _ => (i, Tile::Empty),
-            }
-        })
-        .collect();
-
-    // include the x value with the tile enum variants
-    let east: Vec<(i32, Tile)> = ((wx + 1)..=x_max)
-        .map(|i| {
-            let unit = world.other_units.iter_mut().find(|unit| {
-                let (x, _) = unit.position;
-                x == i
-            });
-            match unit {
-                Some(unit) => (i, Tile::Unit(unit.unit_type)),
-                _ => (i, Tile::Empty),
-            }
-        })
-        .collect();
+    for w in 0..world.warriors.len() {
+        if world.warriors[w].is_active() {
+            let mut warrior_events = warrior_turn(world, w);
+            events.append(&mut warrior_events);
+        }
+    }
 
-    let (ahead, behind) = match facing {
-        Direction::Forward => (east, west),
-        Direction::Backward => (west, east),
-    };
+    events
+}
+
+// play the turn of the warrior at index `w`
+fn warrior_turn(world: &mut World, w: usize) -> Vec<String> {
+    let mut events = Vec::new();
+    let name = world.warriors[w].name.clone();
+
+    let (wx, wy) = world.warriors[w].unit.position;
+    let facing = world.warriors[w].unit.facing.unwrap();
 
-    let warrior = Warrior::new(
-        world.warrior_level,
-        // `Vec<(i32, Tile)>` -> `Vec<Tile>`
-        ahead.clone().into_iter().map(|(_, t)| t).collect(),
-        // `Vec<(i32, Tile)>` -> `Vec<Tile>`
-        behind.clone().into_iter().map(|(_, t)| t).collect(),
-        health,
-        facing,
-    );
+    let warrior = warrior_view(world, w);
+
+    if world.warriors[w].unit.has_effect(Effect::Stunned) {
+        events.push(format!(
+            "{warrior} is stunned and cannot act",
+            warrior = &name
+        ));
+        return events;
+    }
+
+    // a slowed warrior can only act every other turn, counting back from the
+    // last turn of the slow (so that even a 1-turn slow costs an action)
+    let slowed = world.warriors[w]
+        .unit
+        .effects
+        .iter()
+        .find(|status| status.effect == Effect::Slowed);
+    if slowed.is_some_and(|status| status.turns.is_multiple_of(2)) {
+        events.push(format!(
+            "{warrior} is slowed and cannot act this turn",
+            warrior = &name
+        ));
+        return events;
+    }
 
-    world.player.play_turn(&warrior);
+    world.warriors[w].player.play_turn(&warrior);
 
     if let Some(action) = warrior.action() {
         match action {
@@ -85,26 +82,46 @@ pub fn player_system(world: &mut World) -> Vec<String> {
                     wx - 1
                 };
 
-                let other_unit = world.other_units.iter().find(|unit| {
-                    let (x, _) = unit.position;
-                    x == target_x
-                });
-
-                match other_unit {
-                    Some(unit) => {
+                match world.tile((target_x, wy)) {
+                    Tile::Unit(unit_type) => {
+                        let other = match world.warrior_index_at((target_x, wy)) {
+                            Some(v) => world.warriors[v].name.clone(),
+                            None => format!("{:?}", unit_type),
+                        };
+                        events.push(format!(
+                            "{warrior} bumps into {other}",
+                            warrior = &name,
+                            other = other
+                        ));
+                    }
+                    Tile::Door => {
                         events.push(format!(
-                            "{warrior} bumps into {enemy:?}",
-                            warrior = &world.player_name,
-                            enemy = unit.unit_type
+                            "{warrior} bumps into a locked door",
+                            warrior = &name
                         ));
                     }
                     _ => {
                         events.push(format!(
                             "{warrior} walks {direction:?}",
-                            warrior = &world.player_name,
+                            warrior = &name,
                             direction = direction
                         ));
-                        world.warrior.position = (target_x, wy);
+                        world.warriors[w].unit.position = (target_x, wy);
+
+                        if let Some(item) = world.floor.take_item((target_x, wy)) {
+                            events.push(format!(
+                                "{warrior} picks up {item:?}",
+                                warrior = &name,
+                                item = item
+                            ));
+                            world.warriors[w].unit.inventory.push(item);
+                        }
+
+                        if (target_x, wy) == world.floor.stairs && !world.floor.stairs_open() {
+                            events.push(String::from(
+                                "The stairs are sealed until every door is unlocked",
+                            ));
+                        }
                     }
                 }
             }
@@ -119,67 +136,66 @@ pub fn player_system(world: &mut World) -> Vec<String> {
                     wx - 1
                 };
 
-                let other_unit = world.other_units.iter_mut().enumerate().find(|(_, unit)| {
-                    let (x, _) = unit.position;
-                    x == target_x
-                });
+                let atk = match direction {
+                    Direction::Forward => world.warriors[w].unit.atk,
+                    Direction::Backward => (world.warriors[w].unit.atk as f32 / 2.0).ceil() as i32,
+                };
 
-                match other_unit {
-                    Some((i, enemy)) => {
-                        events.push(format!(
-                            "{warrior} attacks {direction:?} and hits {enemy:?}",
-                            warrior = &world.player_name,
-                            direction = direction,
-                            enemy = enemy.unit_type
-                        ));
-                        let atk = match direction {
-                            Direction::Forward => world.warrior.atk,
-                            Direction::Backward => (world.warrior.atk as f32 / 2.0).ceil() as i32,
-                        };
-                        let (current, max) = enemy.hp;
-                        let remaining = cmp::max(current - atk, 0);
-                        events.push(format!(
-                            "{enemy:?} takes {atk} damage, {remaining} HP left",
-                            enemy = enemy.unit_type,
-                            atk = atk,
-                            remaining = remaining
-                        ));
-                        enemy.hp = (remaining, max);
+                if let Some(i) = world.unit_index_at((target_x, wy)) {
+                    let enemy = &mut world.other_units[i];
+                    events.push(format!(
+                        "{warrior} attacks {direction:?} and hits {enemy:?}",
+                        warrior = &name,
+                        direction = direction,
+                        enemy = enemy.unit_type
+                    ));
+                    let (current, max) = enemy.hp;
+                    let remaining = cmp::max(current - atk, 0);
+                    events.push(format!(
+                        "{enemy:?} takes {atk} damage, {remaining} HP left",
+                        enemy = enemy.unit_type,
+                        atk = atk,
+                        remaining = remaining
+                    ));
+                    enemy.hp = (remaining, max);
 
-                        if remaining == 0 {
-                            events.push(format!("{:?} is dead!", enemy.unit_type));
-                            world.remove_unit(i);
-                        }
-                    }
-                    _ => {
-                        events.push(format!(
-                            "{warrior} attacks {direction:?} and hits nothing",
-                            warrior = &world.player_name,
-                            direction = direction
-                        ));
+                    if remaining == 0 {
+                        events.push(format!("{:?} is dead!", enemy.unit_type));
+                        world.defeat_unit(i);
                     }
+                } else if let Some(v) = world.warrior_index_at((target_x, wy)) {
+                    events.push(format!(
+                        "{warrior} attacks {direction:?} and hits {other}",
+                        warrior = &name,
+                        direction = direction,
+                        other = &world.warriors[v].name
+                    ));
+                    let mut strike_events = strike_warrior(world, v, atk);
+                    events.append(&mut strike_events);
+                } else {
+                    events.push(format!(
+                        "{warrior} attacks {direction:?} and hits nothing",
+                        warrior = &name,
+                        direction = direction
+                    ));
                 }
             }
             Action::Rest => {
-                let (current, max) = world.warrior.hp;
-                if current < max {
-                    let restored = if (current + 2) > max {
-                        max - current
-                    } else {
-                        2
-                    };
+                let (current, max) = world.warriors[w].unit.hp;
+                if world.warriors[w].unit.has_effect(Effect::Poisoned) {
+                    events.push(format!("{} tries to rest but is poisoned", &name));
+                } else if current < max {
+                    let rest_hp = max * world.floor.difficulty.rest_percent() / 100;
+                    let restored = cmp::min(rest_hp, max - current);
                     events.push(format!(
                         "{warrior} regains {restored} HP from resting! Now {remaining} HP left",
-                        warrior = &world.player_name,
+                        warrior = &name,
                         restored = restored,
                         remaining = current + restored
                     ));
-                    world.warrior.hp = (current + restored, max);
+                    world.warriors[w].unit.hp = (current + restored, max);
                 } else {
-                    events.push(format!(
-                        "{} rests but is already at max HP",
-                        &world.player_name
-                    ));
+                    events.push(format!("{} rests but is already at max HP", &name));
                 };
             }
             Action::Rescue(direction) => {
@@ -193,25 +209,24 @@ pub fn player_system(world: &mut World) -> Vec<String> {
                     wx - 1
                 };
 
-                let other_unit = world.other_units.iter().enumerate().find(|(_, unit)| {
-                    let (x, _) = unit.position;
-                    x == target_x
-                });
+                let other_unit = world
+                    .unit_index_at((target_x, wy))
+                    .map(|i| (i, &world.other_units[i]));
 
                 match other_unit {
                     Some((i, captive)) if captive.unit_type == UnitType::Captive => {
                         events.push(format!(
                             "{warrior} frees {captive:?} from their bindings",
-                            warrior = &world.player_name,
+                            warrior = &name,
                             captive = captive.unit_type
                         ));
                         events.push(format!("{:?} escapes!", captive.unit_type));
-                        world.remove_unit(i);
+                        world.rescue_unit(i);
                     }
                     Some((_, enemy)) => {
                         events.push(format!(
                                 "{warrior} leans {direction:?} to rescue {enemy:?}, but it is not a captive!",
-                                warrior = &world.player_name,
+                                warrior = &name,
                                 direction = direction,
                                 enemy = enemy.unit_type
                             ));
@@ -219,7 +234,7 @@ pub fn player_system(world: &mut World) -> Vec<String> {
                     None => {
                         events.push(format!(
                             "{warrior} leans {direction:?} to rescue someone, but nobody is here",
-                            warrior = &world.player_name,
+                            warrior = &name,
                             direction = direction
                         ));
                     }
@@ -228,58 +243,49 @@ pub fn player_system(world: &mut World) -> Vec<String> {
             Action::Pivot(direction) => {
                 events.push(format!(
                     "{warrior} pivots to face {direction:?}",
-                    warrior = &world.player_name,
+                    warrior = &name,
                     direction = direction
                 ));
-                world.warrior.facing = Some(direction);
+                world.warriors[w].unit.facing = Some(direction);
             }
             Action::Shoot(direction) => {
-                // find the first unit in the direction the Warrior is shooting, if one exists
-                let target = world.other_units.iter_mut().enumerate().find(|(_, unit)| {
-                        let (x, _) = unit.position;
-                        match direction {
-                            Direction::Forward => {
-                                matches!(ahead.iter().find(|(_, tile)| *tile != Tile::Empty), Some((target_x, _)) if *target_x == x)
-                            }
-                            Direction::Backward => {
-                                matches!(behind.iter().find(|(_, tile)| *tile != Tile::Empty), Some((target_x, _)) if *target_x == x)
-                            }
+                let bow = world.warriors[w]
+                    .unit
+                    .equipment
+                    .and_then(|equipment| equipment.bow);
+                match bow {
+                    Some(bow) if bow.has_ammo() => {
+                        if let Some(ammo) = bow.ammo
+                            && let Some(equipment) = world.warriors[w].unit.equipment.as_mut()
+                        {
+                            equipment.bow = Some(Bow {
+                                ammo: Some(ammo - 1),
+                                ..bow
+                            });
                         }
-                    });
-
-                match target {
-                    Some((i, enemy)) => {
-                        events.push(format!(
-                            "{warrior} lets loose an arrow {direction:?} and hits {enemy:?}",
-                            warrior = &world.player_name,
-                            direction = direction,
-                            enemy = enemy.unit_type
-                        ));
-                        let atk = (world.warrior.atk as f32 / 2.0).ceil() as i32;
-                        let (current, max) = enemy.hp;
-                        let remaining = cmp::max(current - atk, 0);
+                        let step = if facing == direction { 1 } else { -1 };
+                        let mut arrow_events =
+                            loose_arrow(world, w, direction, (step, 0), &bow.ranged);
+                        events.append(&mut arrow_events);
+                    }
+                    Some(_) => {
                         events.push(format!(
-                            "{enemy:?} takes {atk} damage, {remaining} HP left",
-                            enemy = enemy.unit_type,
-                            atk = atk,
-                            remaining = remaining
+                            "{warrior} reaches for an arrow, but the quiver is empty",
+                            warrior = &name
                         ));
-                        enemy.hp = (remaining, max);
-
-                        if remaining == 0 {
-                            events.push(format!("{:?} is dead!", enemy.unit_type));
-                            world.remove_unit(i);
-                        }
                     }
-                    _ => {
+                    None => {
                         events.push(format!(
-                            "{warrior} lets loose an arrow {direction:?} and hits nothing",
-                            warrior = &world.player_name,
-                            direction = direction
+                            "{warrior} has no bow to shoot with",
+                            warrior = &name
                         ));
                     }
                 }
             }
+            Action::Use(item) => {
+                let mut item_events = use_item(world, w, item);
+                events.append(&mut item_events);
+            }
         }
     }
 
@@ -289,3 +295,227 @@ pub fn player_system(world: &mut World) -> Vec<String> {
 
     events
 }
+
+/// The [`Warrior`](crate::warrior::Warrior) handed to the `play_turn` of the
+/// player controlling the warrior at index `w`, describing everything they
+/// can see, feel, and carry.
+pub fn warrior_view(world: &World, w: usize) -> Warrior {
+    let unit = &world.warriors[w].unit;
+    let (health, _) = unit.hp;
+    let facing = unit.facing.unwrap();
+
+    // the warrior can see as far as their bow can shoot
+    let range = match unit.ranged {
+        Some(ranged) => ranged.range,
+        None => 1,
+    };
+
+    // hazards can only be felt once the warrior reaches level 9
+    let can_feel = world.warrior_level >= 9;
+
+    let (ahead, behind) = world.view(unit.position, facing, range, can_feel);
+
+    Warrior::new(world.warrior_level, ahead, behind, health, facing)
+        .with_equipment(unit.equipment.unwrap_or_default())
+        .with_inventory(unit.inventory.clone())
+        .with_effects(unit.effects.clone())
+}
+
+// deal `atk` damage to the warrior at index `v`, struck by another warrior
+fn strike_warrior(world: &mut World, v: usize, atk: i32) -> Vec<String> {
+    let mut events = Vec::new();
+    let damage = world.damage_warrior(v, atk);
+    let other = &world.warriors[v];
+    let (remaining, _) = other.unit.hp;
+    events.push(format!(
+        "{other} takes {damage} damage, {remaining} HP left",
+        other = &other.name,
+        damage = damage,
+        remaining = remaining
+    ));
+    if remaining == 0 {
+        events.push(format!("{} is defeated!", &other.name));
+    }
+    events
+}
+
+// fire an arrow from the position of the warrior at index `w` along `step`
+fn loose_arrow(
+    world: &mut World,
+    w: usize,
+    direction: Direction,
+    step: (i32, i32),
+    ranged: &Ranged,
+) -> Vec<String> {
+    let mut events = Vec::new();
+
+    let name = world.warriors[w].name.clone();
+
+    // find the first unit in the direction the Warrior is shooting, if one exists
+    let from = world.warriors[w].unit.position;
+    let target = ranged::first_target(world, from, step, ranged.range).and_then(|position| {
+        let atk = ranged::hit(world, ranged, from, position)?;
+        Some((position, atk))
+    });
+
+    match target {
+        Some((position, atk)) if world.unit_index_at(position).is_some() => {
+            let i = world.unit_index_at(position).unwrap();
+            let enemy = &mut world.other_units[i];
+            events.push(format!(
+                "{warrior} lets loose an arrow {direction:?} and hits {enemy:?}",
+                warrior = &name,
+                direction = direction,
+                enemy = enemy.unit_type
+            ));
+            let (current, max) = enemy.hp;
+            let remaining = cmp::max(current - atk, 0);
+            events.push(format!(
+                "{enemy:?} takes {atk} damage, {remaining} HP left",
+                enemy = enemy.unit_type,
+                atk = atk,
+                remaining = remaining
+            ));
+            enemy.hp = (remaining, max);
+
+            if remaining == 0 {
+                events.push(format!("{:?} is dead!", enemy.unit_type));
+                world.defeat_unit(i);
+            }
+        }
+        Some((position, atk)) if world.warrior_index_at(position).is_some() => {
+            let v = world.warrior_index_at(position).unwrap();
+            events.push(format!(
+                "{warrior} lets loose an arrow {direction:?} and hits {other}",
+                warrior = &name,
+                direction = direction,
+                other = &world.warriors[v].name
+            ));
+            let mut strike_events = strike_warrior(world, v, atk);
+            events.append(&mut strike_events);
+        }
+        _ => {
+            events.push(format!(
+                "{warrior} lets loose an arrow {direction:?} and hits nothing",
+                warrior = &name,
+                direction = direction
+            ));
+        }
+    }
+
+    events
+}
+
+// use an item from the inventory of the warrior at index `w`; items which
+// have no effect are kept
+fn use_item(world: &mut World, w: usize, item: Item) -> Vec<String> {
+    let mut events = Vec::new();
+    let name = world.warriors[w].name.clone();
+
+    let index = match world.warriors[w]
+        .unit
+        .inventory
+        .iter()
+        .position(|i| *i == item)
+    {
+        Some(index) => index,
+        None => {
+            events.push(format!(
+                "{warrior} searches for {item:?}, but has none",
+                warrior = &name,
+                item = item
+            ));
+            return events;
+        }
+    };
+
+    let used = match item {
+        Item::HealthPotion => {
+            let (current, max) = world.warriors[w].unit.hp;
+            let restored = cmp::min(HEALTH_POTION_HP, max - current);
+            events.push(format!(
+                "{warrior} drinks {item:?} and regains {restored} HP! Now {remaining} HP left",
+                warrior = &name,
+                item = item,
+                restored = restored,
+                remaining = current + restored
+            ));
+            world.warriors[w].unit.hp = (current + restored, max);
+            true
+        }
+        Item::ArrowBundle => {
+            let bow = world.warriors[w]
+                .unit
+                .equipment
+                .as_mut()
+                .and_then(|equipment| equipment.bow.as_mut());
+            match bow {
+                Some(Bow {
+                    ammo: Some(ammo), ..
+                }) => {
+                    *ammo += ARROW_BUNDLE_AMMO;
+                    events.push(format!(
+                        "{warrior} fills the quiver, {ammo} arrows left",
+                        warrior = &name,
+                        ammo = ammo
+                    ));
+                    true
+                }
+                Some(_) => {
+                    events.push(format!(
+                        "{warrior}'s quiver never runs out of arrows",
+                        warrior = &name
+                    ));
+                    false
+                }
+                None => {
+                    events.push(format!(
+                        "{warrior} has no bow to use {item:?} with",
+                        warrior = &name,
+                        item = item
+                    ));
+                    false
+                }
+            }
+        }
+        Item::Key => {
+            // unlock the door in front of the warrior, otherwise the one behind
+            let (wx, wy) = world.warriors[w].unit.position;
+            let step = match world.warriors[w].unit.facing {
+                Some(Direction::Backward) => -1,
+                _ => 1,
+            };
+            let door = [(wx + step, wy), (wx - step, wy)]
+                .into_iter()
+                .find(|position| world.floor.is_door(*position));
+            match door {
+                Some(position) => {
+                    world.floor.open_door(position);
+                    events.push(format!(
+                        "{warrior} unlocks the door with {item:?}",
+                        warrior = &name,
+                        item = item
+                    ));
+                    if world.floor.sealed_stairs && world.floor.stairs_open() {
+                        events.push(String::from("The stairs are no longer sealed!"));
+                    }
+                    true
+                }
+                None => {
+                    events.push(format!(
+                        "{warrior} holds up {item:?}, but there is no door to unlock",
+                        warrior = &name,
+                        item = item
+                    ));
+                    false
+                }
+            }
+        }
+    };
+
+    if used {
+        world.warriors[w].unit.inventory.remove(index);
+    }
+
+    events
+}
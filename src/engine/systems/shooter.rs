@@ -1,55 +1,87 @@
 //! contains system for archer and wizard enemy AI
 
-use std::cmp;
-
-use crate::{engine::world::World, unit::UnitType};
+use crate::{
+    effect::Effect,
+    engine::{ranged, world::World},
+};
 
 /// This system acts as an enemy AI, attacking the player if an archer or
-/// wizard exists and can attack the [`Warrior`](crate::warrior::Warrior).
+/// wizard exists and can attack a [`Warrior`](crate::warrior::Warrior).
 /// The difference from the sludge is that the archer's arrows (and wizard's
-/// wand) can reach the warrior up to three spaces away, as long as there is no
-/// other enemy in the way.
+/// wand) can reach the warrior from afar, as long as the warrior is within
+/// their [`range`](crate::engine::ranged) and nothing is in the way.
+/// If no warrior can be reached, the golem is attacked instead. Stunned
+/// archers and wizards cannot attack, and wizards hold their stunning bolts
+/// while their target is still stunned.
 pub fn shooter_system(world: &mut World) -> Vec<String> {
     let mut events = Vec::new();
 
-    let (wx, _) = world.warrior.position;
-
     let mut shooters = Vec::new();
-    for unit in &world.other_units {
-        if unit.unit_type == UnitType::Archer || unit.unit_type == UnitType::Wizard {
+    for unit in world.other_units.iter() {
+        if unit.ranged.is_some() && !unit.has_effect(Effect::Stunned) {
             shooters.push(unit.clone());
         }
     }
 
     for shooter in shooters {
-        let (sx, _) = shooter.position;
         let (hp, _) = shooter.hp;
+        let ranged = shooter.ranged.unwrap();
 
-        let in_range = (sx - wx).abs() < 4;
-
-        let mut obstructions = Vec::new();
-        for unit in &world.other_units {
-            let (x, _) = unit.position;
-            if (wx < x && x < sx) || (wx > x && x > sx) {
-                obstructions.push(unit.clone());
+        // the first warrior that can be hit is attacked
+        let hit = world.warriors.iter().enumerate().find_map(|(w, warrior)| {
+            if !warrior.is_active() {
+                return None;
             }
+            let atk = ranged::hit(world, &ranged, shooter.position, warrior.unit.position)?;
+            Some((w, atk))
+        });
+
+        // a stunning bolt is held back while its target is still stunned
+        let stuns = shooter
+            .inflicts
+            .is_some_and(|status| status.effect == Effect::Stunned);
+        if let Some((w, _)) = hit
+            && stuns
+            && world.warriors[w].unit.has_effect(Effect::Stunned)
+        {
+            continue;
         }
 
-        if hp > 0 && in_range && obstructions.is_empty() {
+        if let Some((w, atk)) = hit
+            && hp > 0
+        {
+            let name = world.warriors[w].name.clone();
             events.push(format!(
                 "{shooter:?} attacks {warrior}",
                 shooter = shooter.unit_type,
-                warrior = &world.player_name
+                warrior = &name
             ));
-            let (current, max) = world.warrior.hp;
-            let remaining = cmp::max(current - shooter.atk, 0);
+            let damage = world.damage_warrior(w, atk);
+            let (remaining, _) = world.warriors[w].unit.hp;
             events.push(format!(
-                "{warrior} takes {atk} damage, {remaining} HP left",
-                warrior = &world.player_name,
-                atk = shooter.atk,
+                "{warrior} takes {damage} damage, {remaining} HP left",
+                warrior = &name,
+                damage = damage,
                 remaining = remaining
             ));
-            world.warrior.hp = (remaining, max);
+            if let Some(status) = shooter.inflicts {
+                events.push(format!(
+                    "{warrior} is {effect:?} for {turns} turn(s)",
+                    warrior = &name,
+                    effect = status.effect,
+                    turns = status.turns
+                ));
+                world.warriors[w].unit.afflict(status);
+            }
+        } else if let Some(golem) = &world.golem
+            && hp > 0
+        {
+            // with the warrior out of sight, attack the golem instead
+            if let Some(atk) = ranged::hit(world, &ranged, shooter.position, golem.position) {
+                events.push(format!("{:?} attacks Golem", shooter.unit_type));
+                let mut golem_events = world.damage_golem(atk);
+                events.append(&mut golem_events);
+            }
         }
     }
 
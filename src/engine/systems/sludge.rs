@@ -1,44 +1,70 @@
 //! contains system for sludge enemy AI
 
-use std::cmp;
-
-use crate::{engine::world::World, unit::UnitType};
+use crate::{effect::Effect, engine::world::World, unit::UnitType};
 
 /// This system acts as an enemy AI, attacking the player if a sludge
-/// exists and is in range of the [`Warrior`](crate::warrior::Warrior).
+/// exists and is in range of a [`Warrior`](crate::warrior::Warrior)
+/// (or else the golem, if it is in range instead).
+/// The warrior's [`Armor`](crate::equipment::Armor) absorbs part of each
+/// attack. Stunned sludges cannot attack.
 pub fn sludge_system(world: &mut World) -> Vec<String> {
     let mut events = Vec::new();
 
-    let (wx, _) = world.warrior.position;
-
     let mut sludges = Vec::new();
     for unit in &world.other_units {
-        if unit.unit_type == UnitType::Sludge || unit.unit_type == UnitType::ThickSludge {
+        let is_sludge =
+            unit.unit_type == UnitType::Sludge || unit.unit_type == UnitType::ThickSludge;
+        if is_sludge && !unit.has_effect(Effect::Stunned) {
             sludges.push(unit.clone());
         }
     }
 
     for sludge in sludges {
-        let (sx, _) = sludge.position;
+        let (sx, sy) = sludge.position;
         let (hp, _) = sludge.hp;
 
-        let in_range = (wx - sx).abs() <= 1;
+        // the first warrior within reach is attacked
+        let target = world.warriors.iter().position(|warrior| {
+            let (wx, wy) = warrior.unit.position;
+            warrior.is_active() && wy == sy && (wx - sx).abs() <= 1
+        });
 
-        if hp > 0 && in_range {
+        if let Some(w) = target
+            && hp > 0
+        {
+            let name = world.warriors[w].name.clone();
             events.push(format!(
                 "{sludge:?} attacks {warrior}",
                 sludge = sludge.unit_type,
-                warrior = &world.player_name
+                warrior = &name
             ));
-            let (current, max) = world.warrior.hp;
-            let remaining = cmp::max(current - sludge.atk, 0);
+            let damage = world.damage_warrior(w, sludge.atk);
+            let (remaining, _) = world.warriors[w].unit.hp;
             events.push(format!(
-                "{warrior} takes {atk} damage, {remaining} HP left",
-                warrior = &world.player_name,
-                atk = sludge.atk,
+                "{warrior} takes {damage} damage, {remaining} HP left",
+                warrior = &name,
+                damage = damage,
                 remaining = remaining
             ));
-            world.warrior.hp = (remaining, max);
+            if let Some(status) = sludge.inflicts {
+                events.push(format!(
+                    "{warrior} is {effect:?} for {turns} turn(s)",
+                    warrior = &name,
+                    effect = status.effect,
+                    turns = status.turns
+                ));
+                world.warriors[w].unit.afflict(status);
+            }
+        } else if let Some(golem) = &world.golem
+            && hp > 0
+        {
+            // with the warrior out of reach, attack the golem instead
+            let (gx, gy) = golem.position;
+            if gy == sy && (gx - sx).abs() <= 1 {
+                events.push(format!("{:?} attacks Golem", sludge.unit_type));
+                let mut golem_events = world.damage_golem(sludge.atk);
+                events.append(&mut golem_events);
+            }
         }
     }
 
@@ -7,37 +7,70 @@ use crate::{engine::world::World, floor::Floor, unit::Unit};
 
 #[cfg(feature = "ncurses")]
 pub fn ui_system(world: &World, events: Vec<String>, c: &mut curses::Curses) {
-    let floor = update_floor(world);
     c.clear();
-    c.println(&floor.draw());
-    for e in events {
-        c.println(&e);
+    for line in frame(world, events) {
+        c.println(&line);
     }
 }
 
 /// This system simply calls the `draw` method of
-/// [`Floor`](crate::floor::Floor) after each turn is executed.
+/// [`Floor`](crate::floor::Floor) after each turn is executed, followed by
+/// any status effects the warriors suffer from.
 #[cfg(not(feature = "ncurses"))]
 pub fn ui_system(world: &World, events: Vec<String>) {
-    let floor = update_floor(world);
-    println!("{}", floor.draw());
-    for e in events {
-        println!("{}", e);
+    for line in frame(world, events) {
+        println!("{}", line);
     }
 }
 
-fn update_floor(world: &World) -> Floor {
+/// Everything drawn for the turn: the floor, the warriors' status effects,
+/// and the turn's `events`. This is also what gets recorded for a
+/// [`Replay`](crate::replay::Replay).
+pub fn frame(world: &World, events: Vec<String>) -> Vec<String> {
+    let mut lines = vec![update_floor(world).draw()];
+    lines.append(&mut effects_status(world));
+    lines.extend(events);
+    lines
+}
+
+// the floor with every unit (and the golem) at its current position
+pub(crate) fn update_floor(world: &World) -> Floor {
     let mut floor = world.floor.clone();
 
     floor.units = Vec::new();
 
-    let warrior = Unit::new(world.warrior.unit_type, world.warrior.position);
-    floor.units.push(warrior);
+    for warrior in world.warriors.iter().filter(|warrior| !warrior.escaped) {
+        let updated = Unit::new(warrior.unit.unit_type, warrior.unit.position);
+        floor.units.push(updated);
+    }
 
     for unit in &world.other_units {
         let updated = Unit::new(unit.unit_type, unit.position);
         floor.units.push(updated);
     }
 
+    if let Some(golem) = &world.golem {
+        floor.units.push(Unit::new(golem.unit_type, golem.position));
+    }
+
     floor
 }
+
+// e.g. "Bot is Poisoned (1 turns left)", for effects lasting into the next
+// turn, with a line for each warrior suffering from any
+fn effects_status(world: &World) -> Vec<String> {
+    let mut lines = Vec::new();
+    for warrior in world.warriors.iter().filter(|warrior| warrior.is_active()) {
+        let effects: Vec<String> = warrior
+            .unit
+            .effects
+            .iter()
+            .filter(|status| status.turns > 0)
+            .map(|status| format!("{:?} ({} turns left)", status.effect, status.turns))
+            .collect();
+        if !effects.is_empty() {
+            lines.push(format!("{} is {}", &warrior.name, effects.join(", ")));
+        }
+    }
+    lines
+}
@@ -1,13 +1,72 @@
-use crate::{floor::Floor, unit::Unit, Player};
+use crate::{
+    Player,
+    actions::Direction,
+    engine::{
+        outcome::{MatchSummary, Outcome},
+        ranged,
+    },
+    floor::{Floor, Tile},
+    unit::{Unit, UnitType},
+};
+
+/// A warrior on the floor, along with the `Player` controlling it.
+pub struct Combatant {
+    pub name: String,
+    pub player: Box<dyn Player + Send + Sync>,
+    pub unit: Unit,
+    /// warriors on the same team do not fight each other (see
+    /// [`start_versus`](crate::engine::start_versus))
+    pub team: usize,
+    /// where the warrior stood at the start of the current turn
+    pub previous_position: (i32, i32),
+    pub damage_taken: i32,
+    /// `true` once the warrior has taken the stairs and left the floor
+    pub escaped: bool,
+}
+
+impl Combatant {
+    pub fn new(
+        name: String,
+        player: Box<dyn Player + Send + Sync>,
+        unit: Unit,
+        team: usize,
+    ) -> Combatant {
+        Combatant {
+            name,
+            player,
+            previous_position: unit.position,
+            unit,
+            team,
+            damage_taken: 0,
+            escaped: false,
+        }
+    }
+
+    /// Returns `true` if the warrior is still on the floor and alive.
+    pub fn is_active(&self) -> bool {
+        let (hp, _) = self.unit.hp;
+        !self.escaped && hp > 0
+    }
+}
 
 /// The mutating game state managed by the engine.
 pub struct World {
-    pub player_name: String,
     pub warrior_level: usize,
     pub floor: Floor,
-    pub player: Box<dyn Player + Send + Sync>,
-    pub warrior: Unit,
+    /// every warrior on the floor, in the order they take their turns
+    pub warriors: Vec<Combatant>,
     pub other_units: Vec<Unit>,
+    /// the warriors' companion, if there is one
+    pub golem: Option<Unit>,
+    /// the player controlling the golem (it stands still without one)
+    pub golem_player: Option<Box<dyn Player + Send + Sync>>,
+    pub turns: usize,
+    pub captives_rescued: usize,
+    pub captives_lost: usize,
+    pub enemies_defeated: usize,
+    /// everything drawn so far, one frame per turn (see
+    /// [`ui::frame`](crate::engine::systems::ui::frame))
+    pub frames: Vec<Vec<String>>,
 }
 
 impl World {
@@ -20,16 +79,217 @@ impl World {
         other_units: Vec<Unit>,
     ) -> World {
         World {
-            player_name,
             warrior_level,
             floor,
-            player,
-            warrior,
+            warriors: vec![Combatant::new(player_name, player, warrior, 0)],
             other_units,
+            golem: None,
+            golem_player: None,
+            turns: 0,
+            captives_rescued: 0,
+            captives_lost: 0,
+            enemies_defeated: 0,
+            frames: Vec::new(),
         }
     }
 
     pub fn remove_unit(&mut self, index: usize) {
         self.other_units.remove(index);
     }
+
+    /// Removes a unit that has been killed, keeping count of whether it was
+    /// an enemy or a captive.
+    pub fn defeat_unit(&mut self, index: usize) {
+        match self.other_units[index].unit_type {
+            UnitType::Captive => self.captives_lost += 1,
+            _ => self.enemies_defeated += 1,
+        }
+        self.remove_unit(index);
+    }
+
+    /// Removes a captive that has been freed.
+    pub fn rescue_unit(&mut self, index: usize) {
+        self.captives_rescued += 1;
+        self.remove_unit(index);
+    }
+
+    /// Deals `atk` damage to the warrior at index `w` (see
+    /// [`Unit::take_damage`](crate::unit::Unit::take_damage)), keeping count
+    /// of the total damage taken. Returns the damage that was taken.
+    pub fn damage_warrior(&mut self, w: usize, atk: i32) -> i32 {
+        let warrior = &mut self.warriors[w];
+        let damage = warrior.unit.take_damage(atk);
+        warrior.damage_taken += damage;
+        damage
+    }
+
+    /// Like [`damage_warrior`](World::damage_warrior), but the warrior's
+    /// armor does not absorb any of the `damage` (see
+    /// [`Unit::lose_hp`](crate::unit::Unit::lose_hp)).
+    pub fn hurt_warrior(&mut self, w: usize, damage: i32) -> i32 {
+        let warrior = &mut self.warriors[w];
+        let damage = warrior.unit.lose_hp(damage);
+        warrior.damage_taken += damage;
+        damage
+    }
+
+    /// Deals `atk` damage to the golem, which crumbles once it runs out of
+    /// HP. Returns the events describing what happened.
+    pub fn damage_golem(&mut self, atk: i32) -> Vec<String> {
+        let mut events = Vec::new();
+        if let Some(golem) = self.golem.as_mut() {
+            let damage = golem.take_damage(atk);
+            let (remaining, _) = golem.hp;
+            events.push(format!(
+                "Golem takes {damage} damage, {remaining} HP left",
+                damage = damage,
+                remaining = remaining
+            ));
+            if remaining == 0 {
+                events.push(String::from("Golem crumbles to dust!"));
+                self.golem = None;
+            }
+        }
+        events
+    }
+
+    /// Summarizes the level so far, evaluating the floor's objectives.
+    pub fn outcome(&self, failure: Option<String>) -> Outcome {
+        let captives_remaining = self
+            .other_units
+            .iter()
+            .filter(|unit| unit.unit_type == UnitType::Captive)
+            .count();
+        let mut outcome = Outcome {
+            failure,
+            turns: self.turns,
+            hp: self.warriors[0].unit.hp,
+            died: self.warriors.iter().any(|warrior| warrior.unit.hp.0 == 0),
+            inventory: self.warriors[0].unit.inventory.clone(),
+            equipment: self.warriors[0].unit.equipment,
+            damage_taken: self
+                .warriors
+                .iter()
+                .map(|warrior| warrior.damage_taken)
+                .sum(),
+            captives_rescued: self.captives_rescued,
+            captives_lost: self.captives_lost,
+            captives_remaining,
+            enemies_defeated: self.enemies_defeated,
+            enemies_remaining: self.other_units.len() - captives_remaining,
+            objectives: Vec::new(),
+            difficulty: self.floor.difficulty,
+            frames: self.frames.clone(),
+        };
+        outcome.evaluate(&self.floor.objectives);
+        outcome
+    }
+
+    /// Summarizes a versus match so far. The winner is the last warrior
+    /// standing, if every other team has been defeated.
+    pub fn match_summary(&self) -> MatchSummary {
+        let mut standing = self.warriors.iter().filter(|warrior| warrior.is_active());
+        let winner = match standing.next() {
+            Some(first) if standing.all(|warrior| warrior.team == first.team) => {
+                Some(first.name.clone())
+            }
+            _ => None,
+        };
+        MatchSummary {
+            winner,
+            turns: self.turns,
+            warriors: self
+                .warriors
+                .iter()
+                .map(|warrior| (warrior.name.clone(), warrior.unit.hp, warrior.damage_taken))
+                .collect(),
+        }
+    }
+
+    /// Returns the index (into `other_units`) of the unit at `position`.
+    pub fn unit_index_at(&self, position: (i32, i32)) -> Option<usize> {
+        self.other_units
+            .iter()
+            .position(|unit| unit.position == position)
+    }
+
+    /// Returns the index (into `warriors`) of the warrior still on the floor
+    /// at `position`.
+    pub fn warrior_index_at(&self, position: (i32, i32)) -> Option<usize> {
+        self.warriors
+            .iter()
+            .position(|warrior| warrior.is_active() && warrior.unit.position == position)
+    }
+
+    /// Returns a `Tile` representing the current state of the tile at
+    /// `position`, including the warriors, any units still alive, any items
+    /// not yet picked up, any doors still locked, and any hazards.
+    /// Anything outside of the floor is a `Tile::Wall`.
+    pub fn tile(&self, position: (i32, i32)) -> Tile {
+        let (x, y) = position;
+        if x < 0 || y < 0 || x >= self.floor.width as i32 || y >= self.floor.height as i32 {
+            return Tile::Wall;
+        }
+
+        if let Some(w) = self.warrior_index_at(position) {
+            return Tile::Unit(self.warriors[w].unit.unit_type);
+        }
+
+        if let Some(golem) = &self.golem
+            && golem.position == position
+        {
+            return Tile::Unit(golem.unit_type);
+        }
+
+        if let Some(i) = self.unit_index_at(position) {
+            return Tile::Unit(self.other_units[i].unit_type);
+        }
+
+        if position == self.floor.stairs {
+            return Tile::Stairs;
+        }
+
+        if self.floor.is_door(position) {
+            return Tile::Door;
+        }
+
+        if let Some(item) = self.floor.item(position) {
+            return Tile::Item(item);
+        }
+
+        match self.floor.hazard(position) {
+            Some(hazard) => Tile::Hazard(hazard),
+            None => Tile::Empty,
+        }
+    }
+
+    /// The tiles seen (ahead, behind) by a unit at `position` facing
+    /// `facing`, up to `range` tiles away in each direction. Hazards are only
+    /// seen by those who `can_feel` them.
+    pub fn view(
+        &self,
+        position: (i32, i32),
+        facing: Direction,
+        range: i32,
+        can_feel: bool,
+    ) -> (Vec<Tile>, Vec<Tile>) {
+        let look = |step| -> Vec<Tile> {
+            ranged::scan(self, position, step, range)
+                .into_iter()
+                .map(|(_, tile)| match tile {
+                    // the stairs go unnoticed until standing on them
+                    Tile::Stairs => Tile::Empty,
+                    Tile::Hazard(_) if !can_feel => Tile::Empty,
+                    tile => tile,
+                })
+                .collect()
+        };
+        let west = look((-1, 0));
+        let east = look((1, 0));
+
+        match facing {
+            Direction::Forward => (east, west),
+            Direction::Backward => (west, east),
+        }
+    }
 }
@@ -0,0 +1,151 @@
+//! contains types that represent the warrior's equipment
+
+use crate::engine::ranged::Ranged;
+
+/// A melee weapon, used when the warrior attacks.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Weapon {
+    pub name: &'static str,
+    /// the damage dealt when attacking forward
+    pub atk: i32,
+}
+
+impl Weapon {
+    /// The warrior's starting weapon (5 ATK).
+    pub fn short_sword() -> Weapon {
+        Weapon {
+            name: "Short Sword",
+            atk: 5,
+        }
+    }
+
+    /// A heavier weapon, found at **Level 6** (7 ATK).
+    pub fn long_sword() -> Weapon {
+        Weapon {
+            name: "Long Sword",
+            atk: 7,
+        }
+    }
+}
+
+/// A bow, used when the warrior shoots.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bow {
+    pub name: &'static str,
+    /// the damage dealt, the range, and the damage falloff of each arrow
+    pub ranged: Ranged,
+    /// the number of arrows left, or `None` if the quiver never runs out
+    pub ammo: Option<i32>,
+}
+
+impl Bow {
+    /// The bow found at **Level 8** (3 ATK, 3 range, unlimited arrows).
+    pub fn short_bow() -> Bow {
+        Bow {
+            name: "Short Bow",
+            ranged: Ranged::new(3, 3),
+            ammo: None,
+        }
+    }
+
+    /// A bow that shoots further, but whose arrows lose damage with
+    /// distance (4 ATK, 5 range, 1 falloff). It is found at **Level 9** with
+    /// `LONG_BOW_AMMO` arrows, and an
+    /// [`ArrowBundle`](crate::item::Item::ArrowBundle) adds more.
+    pub fn long_bow(ammo: i32) -> Bow {
+        Bow {
+            name: "Long Bow",
+            ranged: Ranged {
+                atk: 4,
+                range: 5,
+                falloff: 1,
+            },
+            ammo: Some(ammo),
+        }
+    }
+
+    /// Returns `true` if there is at least one arrow left.
+    pub fn has_ammo(&self) -> bool {
+        !matches!(self.ammo, Some(ammo) if ammo <= 0)
+    }
+}
+
+/// The number of arrows the Long Bow is found with.
+pub const LONG_BOW_AMMO: i32 = 10;
+
+/// Armor, which reduces the damage the warrior takes from every attack.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Armor {
+    pub name: &'static str,
+    /// the damage absorbed from each attack
+    pub defense: i32,
+}
+
+impl Armor {
+    /// Light armor, found at **Level 4** (1 DEF).
+    pub fn leather() -> Armor {
+        Armor {
+            name: "Leather Armor",
+            defense: 1,
+        }
+    }
+
+    /// Heavy armor, found at **Level 9** (2 DEF).
+    pub fn chain_mail() -> Armor {
+        Armor {
+            name: "Chain Mail",
+            defense: 2,
+        }
+    }
+}
+
+/// The warrior's current loadout: a melee `weapon`, an optional `bow`, and
+/// optional `armor`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Equipment {
+    pub weapon: Weapon,
+    pub bow: Option<Bow>,
+    pub armor: Option<Armor>,
+}
+
+impl Default for Equipment {
+    fn default() -> Equipment {
+        Equipment {
+            weapon: Weapon::short_sword(),
+            bow: None,
+            armor: None,
+        }
+    }
+}
+
+impl Equipment {
+    /// Returns the loadout the warrior has earned by climbing the tower to
+    /// the given `level`: Leather Armor at **Level 4**, a Long Sword at
+    /// **Level 6**, a Short Bow at **Level 8**, and finally Chain Mail and a
+    /// Long Bow at **Level 9**.
+    pub fn for_level(level: usize) -> Equipment {
+        let mut equipment = Equipment::default();
+        if level >= 4 {
+            equipment.armor = Some(Armor::leather());
+        }
+        if level >= 6 {
+            equipment.weapon = Weapon::long_sword();
+        }
+        if level >= 8 {
+            equipment.bow = Some(Bow::short_bow());
+        }
+        if level >= 9 {
+            equipment.armor = Some(Armor::chain_mail());
+            equipment.bow = Some(Bow::long_bow(LONG_BOW_AMMO));
+        }
+        equipment
+    }
+
+    /// The damage absorbed by the armor from each attack.
+    pub fn defense(&self) -> i32 {
+        match self.armor {
+            Some(armor) => armor.defense,
+            None => 0,
+        }
+    }
+}
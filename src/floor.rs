@@ -1,8 +1,16 @@
 //! contains types that represent the topology of a level
 
-use std::collections::HashMap;
+use std::{cmp, collections::HashMap};
 
-use crate::unit::{Unit, UnitType};
+use crate::{
+    actions::Direction,
+    difficulty::Difficulty,
+    equipment::Equipment,
+    hazard::Hazard,
+    item::Item,
+    objective::Objective,
+    unit::{Unit, UnitType},
+};
 
 /// The `Floor::tile` method constructs a conceptual representation of the
 /// floor using the `Tile` enum.
@@ -11,7 +19,10 @@ pub enum Tile {
     Wall,
     Empty,
     Stairs,
+    Door,
     Unit(UnitType),
+    Item(Item),
+    Hazard(Hazard),
 }
 
 impl Tile {
@@ -21,16 +32,20 @@ impl Tile {
             Tile::Wall => panic!("attempted to draw a wall"),
             Tile::Empty => " ",
             Tile::Stairs => ">",
+            Tile::Door => "#",
             Tile::Unit(unit_type) => unit_type.draw(),
+            Tile::Item(item) => item.draw(),
+            Tile::Hazard(hazard) => hazard.draw(),
         }
     }
 }
 
 /// Each level has a `Floor` with a predefined `width` and `height`,
-/// `stairs` positioned at the exit, and one or more `units`. There
-/// is a player-controlled [`Warrior`](crate::warrior::Warrior) unit
-/// for every level.
-#[derive(Clone, Debug)]
+/// `stairs` positioned at the exit, one or more `units`, any `items`
+/// waiting to be picked up, any locked `doors`, any `hazards`, and any
+/// `objectives` to be met before reaching the stairs. There is a player-controlled
+/// [`Warrior`](crate::warrior::Warrior) unit for every level.
+#[derive(Clone, Debug, Default)]
 pub struct Floor {
     /// the east/west count of tiles
     pub width: usize,
@@ -40,6 +55,22 @@ pub struct Floor {
     pub stairs: (i32, i32),
     /// all of the units that the level contains
     pub units: Vec<Unit>,
+    /// all of the items (and their positions) that the level contains
+    pub items: Vec<(Item, (i32, i32))>,
+    /// the positions of locked doors, which block movement and line of
+    /// sight until opened with a key
+    pub doors: Vec<(i32, i32)>,
+    /// whether the stairs stay sealed until every door has been opened
+    pub sealed_stairs: bool,
+    /// all of the hazards (and their positions) that the level contains
+    pub hazards: Vec<(Hazard, (i32, i32))>,
+    /// the goals, besides reaching the stairs, for completing the level
+    pub objectives: Vec<Objective>,
+    /// how tough the level is (see [`Floor::with_difficulty`])
+    pub difficulty: Difficulty,
+    /// the equipment the (first) warrior brings along, in place of the
+    /// loadout earned at the level they play at (see [`Floor::with_warrior`])
+    pub equipment: Option<Equipment>,
 }
 
 impl Floor {
@@ -51,11 +82,164 @@ impl Floor {
         }
     }
 
+    /// Builds a floor from the way it is drawn (see `draw`), one line per
+    /// row, e.g. `"@ s >"`. Every character that a `Tile` can be drawn as is
+    /// understood; a floor without `>` has no stairs.
+    ///
+    /// Panics on any other character.
+    pub fn parse(snippet: &str) -> Floor {
+        let mut floor = Floor {
+            stairs: (-1, -1),
+            ..Floor::default()
+        };
+        for (y, line) in snippet.lines().enumerate() {
+            floor.height = y + 1;
+            floor.width = cmp::max(floor.width, line.chars().count());
+            for (x, c) in line.chars().enumerate() {
+                let position = (x as i32, y as i32);
+                let unit_type = match c {
+                    ' ' => continue,
+                    '>' => {
+                        floor.stairs = position;
+                        continue;
+                    }
+                    '#' => {
+                        floor.doors.push(position);
+                        continue;
+                    }
+                    '!' | '}' | 'k' => {
+                        let item = match c {
+                            '!' => Item::HealthPotion,
+                            '}' => Item::ArrowBundle,
+                            _ => Item::Key,
+                        };
+                        floor.items.push((item, position));
+                        continue;
+                    }
+                    '^' | '~' | '_' => {
+                        let hazard = match c {
+                            '^' => Hazard::Spikes,
+                            '~' => Hazard::PoisonPool,
+                            _ => Hazard::PressurePlate,
+                        };
+                        floor.hazards.push((hazard, position));
+                        continue;
+                    }
+                    'a' => UnitType::Archer,
+                    'C' => UnitType::Captive,
+                    'G' => UnitType::Golem,
+                    's' => UnitType::Sludge,
+                    'S' => UnitType::ThickSludge,
+                    '@' => UnitType::Warrior,
+                    'w' => UnitType::Wizard,
+                    c => panic!("unrecognized tile '{}' in floor", c),
+                };
+                floor.units.push(Unit::new(unit_type, position));
+            }
+        }
+        floor
+    }
+
+    /// A symmetric floor for versus matches (see
+    /// [`engine::start_versus`](crate::engine::start_versus)), with a warrior
+    /// at either end facing the other. An arena has no stairs.
+    pub fn arena() -> Floor {
+        Floor {
+            width: 10,
+            height: 1,
+            stairs: (-1, -1),
+            units: vec![
+                Unit::warrior((0, 0)),
+                Unit {
+                    facing: Some(Direction::Backward),
+                    ..Unit::warrior((9, 0))
+                },
+            ],
+            items: vec![(Item::HealthPotion, (2, 0)), (Item::HealthPotion, (7, 0))],
+            hazards: vec![(Hazard::Spikes, (4, 0)), (Hazard::Spikes, (5, 0))],
+            ..Floor::default()
+        }
+    }
+
+    /// The floor at `difficulty`, with the HP and ATK of its enemies scaled
+    /// accordingly.
+    pub fn with_difficulty(mut self, difficulty: Difficulty) -> Floor {
+        for unit in self.units.iter_mut() {
+            difficulty.scale(unit);
+        }
+        self.difficulty = difficulty;
+        self
+    }
+
+    /// The floor with its (first) warrior starting out with current/max `hp`,
+    /// the items in `inventory` and `equipment` (or else the equipment for the
+    /// level they are played at), e.g. as they were on leaving the floor below
+    /// in a [`gauntlet`](crate::gauntlet).
+    pub fn with_warrior(
+        mut self,
+        hp: (i32, i32),
+        inventory: Vec<Item>,
+        equipment: Option<Equipment>,
+    ) -> Floor {
+        let warrior = self
+            .units
+            .iter_mut()
+            .find(|unit| unit.unit_type == UnitType::Warrior);
+        if let Some(warrior) = warrior {
+            warrior.hp = hp;
+            warrior.inventory = inventory;
+        }
+        self.equipment = equipment;
+        self
+    }
+
+    /// Adds warriors next to the first one (behind it where possible) until
+    /// there are `size` of them, for playing the floor in co-op (see
+    /// [`engine::start_coop`](crate::engine::start_coop)).
+    pub fn with_party(mut self, size: usize) -> Floor {
+        let first = self
+            .units
+            .iter()
+            .find(|unit| unit.unit_type == UnitType::Warrior)
+            .map(|unit| unit.position);
+        let Some((wx, wy)) = first else {
+            return self;
+        };
+        let mut count = self
+            .units
+            .iter()
+            .filter(|unit| unit.unit_type == UnitType::Warrior)
+            .count();
+        for distance in 1..self.width as i32 {
+            for x in [wx - distance, wx + distance] {
+                let inside = 0 <= x && x < self.width as i32;
+                if count < size && inside && self.tile((x, wy)) == Tile::Empty {
+                    self.units.push(Unit::warrior((x, wy)));
+                    count += 1;
+                }
+            }
+        }
+        self
+    }
+
+    /// Returns `true` if the floor looks the same when mirrored east to west,
+    /// so that neither end has an advantage.
+    pub fn is_symmetric(&self) -> bool {
+        let width = self.width as i32;
+        (0..self.height as i32)
+            .all(|y| (0..width).all(|x| self.tile((x, y)) == self.tile((width - 1 - x, y))))
+    }
+
     /// Returns `true` if a configuration exists for a given `level` number.
     pub fn exists(level: usize) -> bool {
         Floor::get(level).is_some()
     }
 
+    /// The number of levels in the tower (the top floor is this level).
+    pub fn count() -> usize {
+        (1..).take_while(|level| Floor::exists(*level)).count()
+    }
+
     /// Returns a `Tile` representing the current state of a tile
     /// of the floor at `position`.
     pub fn tile(&self, position: (i32, i32)) -> Tile {
@@ -63,28 +247,85 @@ impl Floor {
             return Tile::Stairs;
         }
 
+        if self.is_door(position) {
+            return Tile::Door;
+        }
+
         let unit_positions: HashMap<(i32, i32), UnitType> = self
             .units
             .iter()
             .map(|u| (u.position, u.unit_type))
             .collect();
 
-        match unit_positions.get(&position) {
-            Some(unit_type) => Tile::Unit(*unit_type),
+        if let Some(unit_type) = unit_positions.get(&position) {
+            return Tile::Unit(*unit_type);
+        }
+
+        if let Some(item) = self.item(position) {
+            return Tile::Item(item);
+        }
+
+        match self.hazard(position) {
+            Some(hazard) => Tile::Hazard(hazard),
             _ => Tile::Empty,
         }
     }
 
+    /// Returns the hazard at `position`, if there is one.
+    pub fn hazard(&self, position: (i32, i32)) -> Option<Hazard> {
+        self.hazards
+            .iter()
+            .find(|(_, p)| *p == position)
+            .map(|(hazard, _)| *hazard)
+    }
+
+    /// Returns the item lying at `position`, if there is one.
+    pub fn item(&self, position: (i32, i32)) -> Option<Item> {
+        self.items
+            .iter()
+            .find(|(_, p)| *p == position)
+            .map(|(item, _)| *item)
+    }
+
+    /// Returns `true` if there is a locked door at `position`.
+    pub fn is_door(&self, position: (i32, i32)) -> bool {
+        self.doors.contains(&position)
+    }
+
+    /// Opens (removes) the locked door at `position`. Returns `true` if
+    /// there was a door to open.
+    pub fn open_door(&mut self, position: (i32, i32)) -> bool {
+        let count = self.doors.len();
+        self.doors.retain(|door| *door != position);
+        self.doors.len() < count
+    }
+
+    /// Returns `true` if the stairs can be used to exit the floor, which is
+    /// always the case unless the stairs are sealed and a door is still
+    /// locked.
+    pub fn stairs_open(&self) -> bool {
+        !self.sealed_stairs || self.doors.is_empty()
+    }
+
+    /// Removes the item lying at `position` from the floor and returns it.
+    pub fn take_item(&mut self, position: (i32, i32)) -> Option<Item> {
+        let i = self.items.iter().position(|(_, p)| *p == position)?;
+        let (item, _) = self.items.remove(i);
+        Some(item)
+    }
+
     /// Prints a textual representation of the floor and all
     /// of its units.
     pub fn draw(&self) -> String {
         let mut lines = Vec::new();
         lines.push(format!(" {}", "-".repeat(self.width)));
 
-        let tiles: Vec<&str> = (0..self.width)
-            .map(|x| self.tile((x as i32, 0)).draw())
-            .collect();
-        lines.push(format!("|{}|", tiles.join("")));
+        for y in 0..self.height {
+            let tiles: Vec<&str> = (0..self.width)
+                .map(|x| self.tile((x as i32, y as i32)).draw())
+                .collect();
+            lines.push(format!("|{}|", tiles.join("")));
+        }
 
         lines.push(format!(" {}", "-".repeat(self.width)));
         lines.join("\n")
@@ -97,12 +338,14 @@ impl Floor {
                 height: 1,
                 stairs: (7, 0),
                 units: vec![Unit::warrior((0, 0))],
+                ..Floor::default()
             }),
             2 => Some(Floor {
                 width: 8,
                 height: 1,
                 stairs: (7, 0),
                 units: vec![Unit::warrior((0, 0)), Unit::sludge((4, 0))],
+                ..Floor::default()
             }),
             3 => Some(Floor {
                 width: 9,
@@ -115,6 +358,7 @@ impl Floor {
                     Unit::sludge((5, 0)),
                     Unit::sludge((7, 0)),
                 ],
+                ..Floor::default()
             }),
             4 => Some(Floor {
                 width: 8,
@@ -126,6 +370,7 @@ impl Floor {
                     Unit::archer((5, 0)),
                     Unit::thick_sludge((6, 0)),
                 ],
+                ..Floor::default()
             }),
             5 => Some(Floor {
                 width: 8,
@@ -139,6 +384,8 @@ impl Floor {
                     Unit::thick_sludge((5, 0)),
                     Unit::captive((6, 0)),
                 ],
+                objectives: vec![Objective::LoseNoCaptives],
+                ..Floor::default()
             }),
             6 => Some(Floor {
                 width: 9,
@@ -151,6 +398,8 @@ impl Floor {
                     Unit::archer((6, 0)),
                     Unit::archer((7, 0)),
                 ],
+                objectives: vec![Objective::LoseNoCaptives],
+                ..Floor::default()
             }),
             7 => Some(Floor {
                 width: 6,
@@ -161,6 +410,7 @@ impl Floor {
                     Unit::thick_sludge((3, 0)),
                     Unit::warrior((5, 0)),
                 ],
+                ..Floor::default()
             }),
             8 => Some(Floor {
                 width: 7,
@@ -172,6 +422,8 @@ impl Floor {
                     Unit::wizard((3, 0)),
                     Unit::wizard((5, 0)),
                 ],
+                objectives: vec![Objective::LoseNoCaptives],
+                ..Floor::default()
             }),
             9 => Some(Floor {
                 width: 12,
@@ -185,6 +437,8 @@ impl Floor {
                     Unit::wizard((10, 0)),
                     Unit::captive((11, 0)),
                 ],
+                objectives: vec![Objective::LoseNoCaptives],
+                ..Floor::default()
             }),
             _ => None,
         }
@@ -1,15 +1,26 @@
 //! where it all starts
 
 use std::env;
-use std::fs;
+use std::path::Path;
+use std::process;
 
-use crate::{engine, floor::Floor, profile::Profile, starter, ui, Player};
+use crate::{
+    daily, engine,
+    floor::Floor,
+    gauntlet::Gauntlet,
+    generator::{self, Generator},
+    player::PlayerGenerator,
+    profile::{Profile, ProfileError},
+    replay::{REPLAY_PATH, Replay},
+    starter, ui,
+    unit::UnitType,
+};
 
 /// This is exposed to the [`Player`](crate::player::Player) to get things
 /// started. Their profile is loaded (from .profile) and then the
 /// [`engine`](crate::engine) is fired up. If the current level is
 /// completed successfully, then the README.md file and their profile are
-/// updated.
+/// updated. In hardcore mode, a death sends the warrior back to Level 1.
 pub struct Game {
     pub profile: Profile,
 }
@@ -33,65 +44,397 @@ impl Game {
     /// After loading the player profile and initializing the current
     /// level, the game consists of repeatedly calling `play_turn`
     /// on the player's `Player` instance.
-    pub fn play(player_generator: fn() -> Box<dyn Player + Send + Sync>) {
+    ///
+    /// With `--practice [<seed>]`, a practice floor is played instead (see
+    /// `play_practice`). Once Maximus Oxidus, the player is also offered the
+    /// [`daily`](crate::daily) challenge (taken on without asking with
+    /// `--daily`).
+    pub fn play(player_generator: PlayerGenerator) {
+        if practice_flag().is_some() {
+            Game::play_practice(player_generator);
+            return;
+        }
+        let mut game = Game::new();
+        if game.offer_daily() {
+            game.play_daily(player_generator);
+            return;
+        }
+        let players = vec![(game.profile.name.clone(), player_generator)];
+        game.start(players, None);
+    }
+
+    /// Play a practice floor made by the
+    /// [`generator`](crate::generator), with whatever the warrior has
+    /// learned to deal with so far. The floor is built from the seed given
+    /// with `--practice <seed>` (or a random one), and the seed is printed so
+    /// that the floor can be played again. The profile is left as it is.
+    pub fn play_practice(player_generator: PlayerGenerator) {
+        let game = Game::new();
+        let seed = practice_flag()
+            .flatten()
+            .unwrap_or_else(generator::random_seed);
+        let level = if game.profile.maximus_oxidus {
+            Floor::count()
+        } else {
+            game.profile.level
+        };
+        let floor = Generator::new(seed, level)
+            .with_difficulty(game.profile.difficulty)
+            .generate();
+        println!("Starting a practice floor (seed {})", seed);
+        println!("Play it again with `cargo run -- --practice {}`", seed);
+        for objective in &floor.objectives {
+            println!("Objective: {}", objective);
+        }
+        let outcome = engine::start(game.profile.name.clone(), level, floor, player_generator);
+        if let Some(failure) = &outcome.failure {
+            println!("{}", failure);
+        }
+        for (objective, met) in &outcome.objectives {
+            let status = if *met { "met" } else { "FAILED" };
+            println!("Objective {}: {}", status, objective);
+        }
+        if outcome.is_success() {
+            println!("Success! You have found the stairs.");
+            println!("Practice Score: {}", outcome.score());
+        }
+    }
+
+    /// Like `play`, but a second `Player` controls a Golem companion that
+    /// fights alongside the warrior. The golem's `play_turn` is given a
+    /// warrior that can only walk, attack, pivot, and look around.
+    pub fn play_with_golem(player_generator: PlayerGenerator, golem_generator: PlayerGenerator) {
         let mut game = Game::new();
-        game.start(player_generator);
+        let players = vec![(game.profile.name.clone(), player_generator)];
+        game.start(players, Some(golem_generator));
+    }
+
+    /// Play the current level in co-op, with a warrior for each of the
+    /// named `players` (see
+    /// [`Floor::with_party`](crate::floor::Floor::with_party)). Every
+    /// warrior must reach the stairs to complete the level.
+    pub fn play_coop(players: Vec<(&str, PlayerGenerator)>) {
+        let mut game = Game::new();
+        let players = players
+            .into_iter()
+            .map(|(name, player_generator)| (String::from(name), player_generator))
+            .collect();
+        game.start(players, None);
+    }
+
+    /// Pit two named `Player`s against each other in the
+    /// [`arena`](crate::floor::Floor::arena), and print a summary of the
+    /// match. Both warriors have every ability, and no profile is needed.
+    pub fn play_versus(first: (&str, PlayerGenerator), second: (&str, PlayerGenerator)) {
+        let (first_name, first_generator) = first;
+        let (second_name, second_generator) = second;
+        let players = [
+            (String::from(first_name), first_generator),
+            (String::from(second_name), second_generator),
+        ];
+        println!(
+            "Starting a versus match: {} vs. {}",
+            first_name, second_name
+        );
+        // every ability has been learned by the time warriors meet in the arena
+        match engine::start_versus(Floor::count(), Floor::arena(), players) {
+            Ok(summary) => println!("{}", summary),
+            Err(e) => println!("Unable to start the match: {}", e),
+        }
+    }
+
+    /// Run the [`gauntlet`](crate::gauntlet): climb from Level 1 without a
+    /// break, carrying the warrior's HP and items over from floor to floor,
+    /// until they fail a floor or reach the highest level they have reached
+    /// so far (the top of the tower, once Maximus Oxidus). Every floor is
+    /// played with the abilities learned so far, and the profile is left as
+    /// it is.
+    pub fn play_gauntlet(player_generator: PlayerGenerator) {
+        let game = Game::new();
+        let top = if game.profile.maximus_oxidus {
+            Floor::count()
+        } else {
+            game.profile.level
+        };
+        let mut gauntlet = Gauntlet::to_level(game.profile.difficulty, top);
+        println!("Starting the gauntlet (Levels 1 to {})", gauntlet.top);
+        while let Some(floor) = gauntlet.next_floor() {
+            let level = gauntlet.level();
+            let (hp, max) = floor
+                .units
+                .iter()
+                .find(|unit| unit.unit_type == UnitType::Warrior)
+                .map_or((0, 0), |unit| unit.hp);
+            println!("Starting Level {} with {}/{} HP", level, hp, max);
+            let name = game.profile.name.clone();
+            // every floor is played with the abilities learned by the top
+            let outcome = engine::start(name.clone(), top, floor, player_generator);
+            if !outcome.is_success() {
+                match &outcome.failure {
+                    Some(failure) => println!("{}", failure),
+                    None => println!("You found the stairs, but did not meet every objective."),
+                }
+                let replay = Replay {
+                    player: name,
+                    level,
+                    score: outcome.score(),
+                    frames: outcome.frames.clone(),
+                };
+                if let Err(e) = replay.save(Path::new(REPLAY_PATH)) {
+                    println!("Unable to save a replay of this attempt: {}", e);
+                }
+            } else {
+                println!("Level Score: {}", outcome.score());
+            }
+            gauntlet.record(outcome);
+        }
+        if gauntlet.is_complete() {
+            println!("You have run the gauntlet to Level {}!", gauntlet.top);
+        }
+        println!(
+            "Floors climbed: {}, Total Score: {}, Turns: {}",
+            gauntlet.floors_climbed(),
+            gauntlet.score(),
+            gauntlet.turns()
+        );
+    }
+
+    // whether to take on the daily challenge rather than a level
+    fn offer_daily(&self) -> bool {
+        if !self.profile.maximus_oxidus {
+            if daily_flag() {
+                println!(
+                    "The daily challenge opens once you have earned the title Maximus Oxidus."
+                );
+            }
+            return false;
+        }
+        if daily_flag() {
+            return true;
+        }
+        if level_flag().is_some() || prompt_flag().is_some() {
+            return false;
+        }
+        ui::ask("Would you like to take on today's daily challenge?")
+    }
+
+    fn play_daily(&mut self, player_generator: PlayerGenerator) {
+        let date = daily::today();
+        let floor = daily::floor(&date);
+        println!("Starting the daily challenge for {}", date);
+        let best = self
+            .profile
+            .daily_history
+            .iter()
+            .find(|history| history.date == date)
+            .and_then(|history| history.best_score);
+        if let Some(best) = best {
+            println!("Your best score today: {}", best);
+        }
+        for objective in &floor.objectives {
+            println!("Objective: {}", objective);
+        }
+        let outcome = engine::start(
+            self.profile.name.clone(),
+            Floor::count(),
+            floor,
+            player_generator,
+        );
+        if let Some(failure) = &outcome.failure {
+            println!("{}", failure);
+        }
+        for (objective, met) in &outcome.objectives {
+            let status = if *met { "met" } else { "FAILED" };
+            println!("Objective {}: {}", status, objective);
+        }
+        let improved = self.profile.record_daily(&date, &outcome);
+        starter::write_profile(&self.profile, None).expect("failed to write .profile");
+        if outcome.is_success() {
+            println!("Success! You have found the stairs.");
+            println!("Daily Score: {}", outcome.score());
+            if let (true, Some(was)) = (improved, best) {
+                println!("New daily best: {} (was {})", outcome.score(), was);
+            }
+        }
     }
 
-    fn start(&mut self, player_generator: fn() -> Box<dyn Player + Send + Sync>) {
+    fn start(
+        &mut self,
+        players: Vec<(String, PlayerGenerator)>,
+        golem_generator: Option<PlayerGenerator>,
+    ) {
         let level;
         if self.profile.maximus_oxidus {
-            println!("Now that you have earned the title Maximus Oxidus, you may choose to hone your skills on any level.");
-            level = ui::select_level();
-            starter::write_readme(&self.profile, level, None);
+            println!(
+                "Now that you have earned the title Maximus Oxidus, you may choose to hone your skills on any level."
+            );
+            level = match level_flag() {
+                Some(level) => level,
+                None => ui::select_level(Floor::count()),
+            };
+            starter::write_readme(&self.profile, level, None).expect("failed to write README.md");
             println!("See (updated) README.md for level {} instructions.", level);
         } else {
             level = self.profile.level;
         }
         println!("Starting Level {}", level);
-        let floor = Floor::load(level);
-        match engine::start(
-            self.profile.name.clone(),
-            self.profile.level,
-            floor,
-            player_generator,
-        ) {
-            Ok(_) => {
-                self.level_completed();
+        let floor = Floor::load(level).with_difficulty(self.profile.difficulty);
+        for objective in &floor.objectives {
+            println!("Objective: {}", objective);
+        }
+        let name = players[0].0.clone();
+        let outcome = if players.len() > 1 {
+            let floor = floor.with_party(players.len());
+            match engine::start_coop(self.profile.level, floor, players) {
+                Ok(outcome) => outcome,
+                Err(e) => {
+                    println!("Unable to start Level {}: {}", level, e);
+                    return;
+                }
             }
-            Err(err) => {
-                println!("{}", err);
+        } else {
+            let (name, player_generator) = players.into_iter().next().unwrap();
+            engine::start_with_golem(
+                name,
+                self.profile.level,
+                floor,
+                player_generator,
+                golem_generator,
+            )
+        };
+        if let Some(failure) = &outcome.failure {
+            println!("{}", failure);
+        }
+        let replay = Replay {
+            player: name,
+            level,
+            score: outcome.score(),
+            frames: outcome.frames.clone(),
+        };
+        if let Err(e) = replay.save(Path::new(REPLAY_PATH)) {
+            println!("Unable to save a replay of this attempt: {}", e);
+        }
+        for (objective, met) in &outcome.objectives {
+            let status = if *met { "met" } else { "FAILED" };
+            println!("Objective {}: {}", status, objective);
+        }
+        let bests = self.profile.record_attempt(level, &outcome);
+        if outcome.died && self.profile.hardcore {
+            self.profile.fall();
+            starter::write_readme(&self.profile, 1, None).expect("failed to write README.md");
+            println!(
+                "Hardcore: {} has fallen and must climb the tower again from Level 1. See (updated) README.md for instructions.",
+                &self.profile.name
+            );
+        }
+        starter::write_profile(&self.profile, None).expect("failed to write .profile");
+        if outcome.is_success() {
+            println!("Level Score: {}", outcome.score());
+            for best in bests {
+                println!("New personal best: {}", best);
             }
+            self.level_completed();
+        } else if outcome.failure.is_none() {
+            println!("You found the stairs, but did not meet every objective.");
         }
     }
 
     fn level_completed(&mut self) {
-        // TODO: tally points
         if self.profile.maximus_oxidus {
             println!("Success! You have found the stairs.");
         } else if Floor::exists(self.profile.level + 1) {
             println!("Success! You have found the stairs.");
-            if env::var("NO_PROMPT").is_ok() {
-                return;
-            }
-            if ui::ask("Would you like to continue on to the next level?") {
+            let proceed = match prompt_flag() {
+                Some(answer) => answer,
+                None => ui::ask("Would you like to continue on to the next level?"),
+            };
+            if proceed {
                 self.profile.increment_level();
-                starter::write_readme(&self.profile, self.profile.level, None);
-                starter::write_profile(&self.profile, None);
+                starter::write_readme(&self.profile, self.profile.level, None)
+                    .expect("failed to write README.md");
+                starter::write_profile(&self.profile, None).expect("failed to write .profile");
                 println!("See (updated) README.md for your next instructions.");
             } else {
                 // TODO: "Try to earn more points next time."
                 println!("Staying on current level.");
             }
         } else {
-            println!("CONGRATULATIONS! You have climbed to the top of the tower and have earned the title Maximus Oxidus.");
+            println!(
+                "CONGRATULATIONS! You have climbed to the top of the tower and have earned the title Maximus Oxidus."
+            );
             self.profile.maximus_oxidus = true;
-            starter::write_profile(&self.profile, None);
+            starter::write_profile(&self.profile, None).expect("failed to write .profile");
         }
     }
 }
 
+// The prompts can be answered ahead of time when running the game, e.g.
+// `cargo run -- --yes`:
+//
+// * `--yes` continues on to the next level after a success
+// * `--no-prompt` (or the `NO_PROMPT` env var) stays on the current level
+// * `--level <n>` picks the level to play once Maximus Oxidus
+// * `--practice [<seed>]` plays a practice floor instead of a level
+// * `--daily` takes on the daily challenge once Maximus Oxidus
+fn prompt_flag() -> Option<bool> {
+    let args: Vec<String> = env::args().collect();
+    if args.iter().any(|arg| arg == "--yes") {
+        Some(true)
+    } else if args.iter().any(|arg| arg == "--no-prompt") || env::var("NO_PROMPT").is_ok() {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+fn level_flag() -> Option<usize> {
+    let args: Vec<String> = env::args().collect();
+    let i = args.iter().position(|arg| arg == "--level")?;
+    let level = args.get(i + 1)?.parse().ok()?;
+    Floor::exists(level).then_some(level)
+}
+
+fn daily_flag() -> bool {
+    env::args().any(|arg| arg == "--daily")
+}
+
+// the seed (if any) given with `--practice`, or `None` without the flag
+fn practice_flag() -> Option<Option<u64>> {
+    let args: Vec<String> = env::args().collect();
+    let i = args.iter().position(|arg| arg == "--practice")?;
+    Some(args.get(i + 1).and_then(|seed| seed.parse().ok()))
+}
+
 fn load_profile() -> Profile {
-    let contents = fs::read_to_string(".profile").expect("error loading .profile");
-    Profile::from_toml(&contents)
+    match Profile::load(Path::new(".profile")) {
+        Ok(profile) => profile,
+        Err(e) => recover_profile(e),
+    }
+}
+
+// rather than giving up on a missing or broken .profile, offer to recreate it
+// (and to pick up from any level the player can still prove they reached)
+fn recover_profile(error: ProfileError) -> Profile {
+    println!("Unable to load your profile: {}", error);
+    if let ProfileError::UnsupportedVersion(_) = error {
+        println!("Update the rust-warrior dependency in Cargo.toml to play with this profile.");
+        process::exit(1);
+    }
+    if prompt_flag().is_some() || !ui::ask("Would you like to recreate your profile?") {
+        println!("Unable to continue without a profile.");
+        process::exit(1);
+    }
+    // only the levels the old profile can prove may be picked up again
+    let name = ui::request("Enter the name of your warrior: ");
+    let mut profile = Profile::recover(name, Path::new(".profile"));
+    let top = profile.proven_level();
+    if top > 1 {
+        profile.level = ui::select_level(top);
+    }
+    starter::write_profile(&profile, None).expect("failed to write .profile");
+    println!(
+        "Your profile has been recreated at Level {}.",
+        profile.level
+    );
+    profile
 }
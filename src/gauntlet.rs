@@ -0,0 +1,118 @@
+//! contains the gauntlet, a climb up the tower without a break
+//!
+//! Rather than starting every floor fresh with full health, a warrior in the
+//! gauntlet goes straight on to the next floor after reaching the stairs,
+//! keeping whatever HP, items and equipment (down to the last arrow) they left
+//! the last one with. The climb ends when the warrior fails a floor (e.g.
+//! dies, or misses an objective) or tops the tower, so a `Player` has to
+//! manage its resources across floors.
+//!
+//! ```no_run
+//! use rust_warrior::{difficulty::Difficulty, engine, gauntlet::Gauntlet, Player, Warrior};
+//!
+//! struct Alice;
+//! impl Player for Alice {
+//!     fn play_turn(&mut self, warrior: &Warrior) {
+//!         warrior.walk();
+//!     }
+//! }
+//!
+//! let mut gauntlet = Gauntlet::new(Difficulty::Normal);
+//! while let Some(floor) = gauntlet.next_floor() {
+//!     // every floor is played with the abilities learned by the top
+//!     let outcome = engine::start_headless(String::from("Alice"), gauntlet.top, floor, || Box::new(Alice));
+//!     gauntlet.record(outcome);
+//! }
+//! println!("Alice climbed {} floors", gauntlet.floors_climbed());
+//! ```
+
+use crate::{difficulty::Difficulty, engine::outcome::Outcome, floor::Floor};
+
+/// A climb up the tower from Level 1, one [`Outcome`] per floor attempted.
+#[derive(Clone, Debug)]
+pub struct Gauntlet {
+    pub difficulty: Difficulty,
+    /// the last level of the climb (the top of the tower, unless the
+    /// warrior has not gotten that far yet)
+    pub top: usize,
+    pub outcomes: Vec<Outcome>,
+}
+
+impl Gauntlet {
+    /// A climb to the top of the tower at `difficulty`.
+    pub fn new(difficulty: Difficulty) -> Gauntlet {
+        Gauntlet::to_level(difficulty, Floor::count())
+    }
+
+    /// A climb from Level 1 to level `top` (or the top of the tower, if it
+    /// is lower) at `difficulty`.
+    pub fn to_level(difficulty: Difficulty, top: usize) -> Gauntlet {
+        Gauntlet {
+            difficulty,
+            top: top.min(Floor::count()),
+            outcomes: Vec::new(),
+        }
+    }
+
+    /// The level of the floor the warrior is on (or was on when the climb
+    /// ended).
+    pub fn level(&self) -> usize {
+        if self.is_over() {
+            self.outcomes.len()
+        } else {
+            self.outcomes.len() + 1
+        }
+    }
+
+    /// The floor to play next, with the warrior carrying over their HP, items
+    /// and equipment from the floor below. Returns `None` once the climb is over.
+    pub fn next_floor(&self) -> Option<Floor> {
+        if self.is_over() {
+            return None;
+        }
+        let floor = Floor::load(self.level()).with_difficulty(self.difficulty);
+        match self.outcomes.last() {
+            Some(last) => Some(floor.with_warrior(last.hp, last.inventory.clone(), last.equipment)),
+            None => Some(floor),
+        }
+    }
+
+    /// Record how the floor returned by `next_floor` went.
+    pub fn record(&mut self, outcome: Outcome) {
+        self.outcomes.push(outcome);
+    }
+
+    /// Returns `true` once the warrior has failed a floor or reached the top.
+    pub fn is_over(&self) -> bool {
+        let failed = self
+            .outcomes
+            .last()
+            .is_some_and(|outcome| !outcome.is_success());
+        failed || self.outcomes.len() >= self.top
+    }
+
+    /// Returns `true` if the warrior cleared every floor.
+    pub fn is_complete(&self) -> bool {
+        self.is_over() && self.floors_climbed() == self.top
+    }
+
+    /// The number of floors the warrior cleared (reaching the stairs and
+    /// meeting every objective).
+    pub fn floors_climbed(&self) -> usize {
+        self.outcomes
+            .iter()
+            .filter(|outcome| outcome.is_success())
+            .count()
+    }
+
+    /// The total score of every floor (see
+    /// [`Outcome::score`](crate::engine::outcome::Outcome::score)).
+    pub fn score(&self) -> i32 {
+        self.outcomes.iter().map(|outcome| outcome.score()).sum()
+    }
+
+    /// The total turns played on every floor.
+    pub fn turns(&self) -> usize {
+        self.outcomes.iter().map(|outcome| outcome.turns).sum()
+    }
+}
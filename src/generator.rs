@@ -0,0 +1,346 @@
+//! contains a generator of practice floors
+//!
+//! Besides the predefined floors of the tower, a [`Generator`] can build any
+//! number of practice floors. Every floor is built from a seed, so printing
+//! the seed is enough to play the same floor again. The units, items, and
+//! hazards of a floor are limited to those that the warrior has learned to
+//! deal with by their level (e.g. no wizards before **Level 8**), and every
+//! floor is checked to be solvable before it is handed out.
+//!
+//! ```
+//! use rust_warrior::{difficulty::Difficulty, generator::Generator};
+//!
+//! let generator = Generator::new(42, 5)
+//!     .with_width(12)
+//!     .with_height(2)
+//!     .with_difficulty(Difficulty::Hard);
+//! let floor = generator.generate();
+//! assert_eq!(floor.draw(), generator.generate().draw());
+//! ```
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{
+    Player, Tile, Warrior,
+    actions::Direction,
+    difficulty::Difficulty,
+    effect::Effect,
+    engine,
+    floor::Floor,
+    hazard::Hazard,
+    item::Item,
+    objective::Objective,
+    unit::{Unit, UnitType},
+};
+
+/// The width of a generated floor, unless another is chosen.
+pub const DEFAULT_WIDTH: usize = 10;
+/// The narrowest floor that can be generated.
+pub const MIN_WIDTH: usize = 4;
+/// The widest floor that can be generated.
+pub const MAX_WIDTH: usize = 20;
+/// The tallest floor that can be generated.
+pub const MAX_HEIGHT: usize = 3;
+
+// the number of floors built from a seed before settling for an empty one
+const MAX_ATTEMPTS: usize = 50;
+
+/// Settings for building a practice floor. The same settings always build
+/// the same floor.
+///
+/// The warrior walks the top row of the floor. Any rows below it are
+/// galleries that the warrior cannot enter, where archers and wizards may be
+/// posted to shoot up at the warrior as they pass.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Generator {
+    pub seed: u64,
+    /// the level of the warrior who will play the floor, which decides what
+    /// may be placed on it
+    pub level: usize,
+    /// the east/west count of tiles
+    pub width: usize,
+    /// the north/south count of tiles
+    pub height: usize,
+    /// how tough the floor is (see
+    /// [`Floor::with_difficulty`](crate::floor::Floor::with_difficulty));
+    /// tougher floors are also more crowded
+    pub difficulty: Difficulty,
+}
+
+impl Generator {
+    /// Settings for a floor built from `seed`, for a warrior at `level`
+    /// (up to the top of the tower), at the default width, a single row
+    /// tall, and at the default difficulty.
+    pub fn new(seed: u64, level: usize) -> Generator {
+        Generator {
+            seed,
+            level: level.clamp(1, Floor::count()),
+            width: DEFAULT_WIDTH,
+            height: 1,
+            difficulty: Difficulty::Normal,
+        }
+    }
+
+    /// Build a floor `width` tiles wide (between `MIN_WIDTH` and `MAX_WIDTH`).
+    pub fn with_width(mut self, width: usize) -> Generator {
+        self.width = width.clamp(MIN_WIDTH, MAX_WIDTH);
+        self
+    }
+
+    /// Build a floor `height` tiles tall (between 1 and `MAX_HEIGHT`).
+    pub fn with_height(mut self, height: usize) -> Generator {
+        self.height = height.clamp(1, MAX_HEIGHT);
+        self
+    }
+
+    /// Build a floor at `difficulty`.
+    pub fn with_difficulty(mut self, difficulty: Difficulty) -> Generator {
+        self.difficulty = difficulty;
+        self
+    }
+
+    /// Build the floor. Floors are built from the seed until one turns out
+    /// to be solvable (see [`is_solvable`]); if none does, the floor is an
+    /// empty corridor with the stairs at the far end.
+    pub fn generate(&self) -> Floor {
+        let mut rng = Rng(self.seed);
+        for _ in 0..MAX_ATTEMPTS {
+            let floor = self.build(&mut rng);
+            if is_solvable(&floor, self.level) {
+                return floor;
+            }
+        }
+        self.corridor(false).with_difficulty(self.difficulty)
+    }
+
+    // a floor with just the warrior and the stairs, which are behind the
+    // warrior if `reversed`
+    fn corridor(&self, reversed: bool) -> Floor {
+        let last = self.width as i32 - 1;
+        let (warrior, stairs) = if reversed { (last, 0) } else { (0, last) };
+        Floor {
+            width: self.width,
+            height: self.height,
+            stairs: (stairs, 0),
+            units: vec![Unit::warrior((warrior, 0))],
+            ..Floor::default()
+        }
+    }
+
+    // fill a corridor at random with whatever the warrior can deal with
+    fn build(&self, rng: &mut Rng) -> Floor {
+        // the stairs are only ever behind a warrior who has learned to pivot,
+        // and captives are only left behind one who can turn back for them
+        let reversed = self.level >= 7 && rng.percent(50);
+        let mut floor = self.corridor(reversed);
+        let behind = if self.level >= 6 && !reversed {
+            rng.below(3)
+        } else {
+            0
+        };
+        let position = |distance: usize| {
+            if reversed {
+                (self.width - 1 - distance) as i32
+            } else {
+                distance as i32
+            }
+        };
+        if behind > 0 {
+            floor.units[0].position = (position(behind), 0);
+            for distance in 0..behind {
+                if rng.percent(50) {
+                    floor.units.push(Unit::captive((position(distance), 0)));
+                }
+            }
+        }
+
+        let palette = self.palette();
+        let density = match self.difficulty {
+            Difficulty::Easy => 25,
+            Difficulty::Normal => 35,
+            Difficulty::Hard => 45,
+        };
+        for distance in (behind + 1)..(self.width - 1) {
+            if palette.is_empty() || !rng.percent(density) {
+                continue;
+            }
+            let tile = (position(distance), 0);
+            match palette[rng.below(palette.len())] {
+                Tile::Unit(unit_type) => floor.units.push(Unit::new(unit_type, tile)),
+                Tile::Item(item) => floor.items.push((item, tile)),
+                Tile::Hazard(hazard) => floor.hazards.push((hazard, tile)),
+                _ => {}
+            }
+        }
+
+        // only archers and wizards can reach the warrior from the galleries
+        let shooters: Vec<Tile> = palette
+            .into_iter()
+            .filter(|tile| matches!(tile, Tile::Unit(UnitType::Archer | UnitType::Wizard)))
+            .collect();
+        for y in 1..self.height {
+            for distance in (behind + 1)..(self.width - 1) {
+                if shooters.is_empty() || !rng.percent(density / 2) {
+                    continue;
+                }
+                let tile = (position(distance), y as i32);
+                if let Tile::Unit(unit_type) = shooters[rng.below(shooters.len())] {
+                    floor.units.push(Unit::new(unit_type, tile));
+                }
+            }
+        }
+
+        if floor
+            .units
+            .iter()
+            .any(|unit| unit.unit_type == UnitType::Captive)
+        {
+            floor.objectives.push(Objective::LoseNoCaptives);
+        }
+        floor.with_difficulty(self.difficulty)
+    }
+
+    // everything that may be placed ahead of the warrior at their level
+    fn palette(&self) -> Vec<Tile> {
+        let mut palette = Vec::new();
+        if self.level >= 2 {
+            palette.push(Tile::Unit(UnitType::Sludge));
+        }
+        if self.level >= 3 {
+            palette.push(Tile::Item(Item::HealthPotion));
+        }
+        if self.level >= 4 {
+            palette.push(Tile::Unit(UnitType::ThickSludge));
+            palette.push(Tile::Unit(UnitType::Archer));
+        }
+        if self.level >= 5 {
+            palette.push(Tile::Unit(UnitType::Captive));
+        }
+        if self.level >= 8 {
+            palette.push(Tile::Unit(UnitType::Wizard));
+        }
+        if self.level >= 9 {
+            palette.push(Tile::Item(Item::ArrowBundle));
+            palette.push(Tile::Hazard(Hazard::Spikes));
+            palette.push(Tile::Hazard(Hazard::PoisonPool));
+            palette.push(Tile::Hazard(Hazard::PressurePlate));
+        }
+        palette
+    }
+}
+
+/// Returns `true` if a warrior at `level` can reach the stairs of `floor`
+/// and meet every objective. This is proven by playing the floor (headlessly)
+/// with a straightforward `Player` that only uses the abilities learned by
+/// that level.
+pub fn is_solvable(floor: &Floor, level: usize) -> bool {
+    let outcome = engine::start_headless(String::from("Pathfinder"), level, floor.clone(), || {
+        Box::new(Pathfinder::default())
+    });
+    outcome.is_success()
+}
+
+/// A seed taken from the clock, for when any floor will do.
+pub fn random_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_nanos() as u64)
+}
+
+// the HP below which the pathfinder drinks a potion, rests, or backs off
+const POTION_BELOW: i32 = 8;
+const REST_BELOW: i32 = 15;
+const RETREAT_BELOW: i32 = 10;
+
+// the player used to prove that a floor is solvable: it rescues anything
+// behind it, then fights its way to the stairs, resting when it is safe to
+#[derive(Default)]
+struct Pathfinder {
+    health: i32,
+    behind_cleared: bool,
+}
+
+impl Player for Pathfinder {
+    fn play_turn(&mut self, warrior: &Warrior) {
+        let level = warrior.level();
+        if level < 2 {
+            warrior.walk();
+            return;
+        }
+        let health = if level >= 3 { warrior.health() } else { 20 };
+        let hurt = health < self.health;
+        self.health = health;
+
+        if health < POTION_BELOW && warrior.inventory().contains(&Item::HealthPotion) {
+            warrior.use_item(Item::HealthPotion);
+            return;
+        }
+        if level >= 7 && warrior.check() == Tile::Wall {
+            warrior.pivot();
+            return;
+        }
+        if level >= 6 && !self.behind_cleared {
+            match warrior.check_toward(Direction::Backward) {
+                Tile::Unit(UnitType::Captive) => {
+                    warrior.rescue_toward(Direction::Backward);
+                    return;
+                }
+                Tile::Wall => self.behind_cleared = true,
+                _ => {
+                    warrior.walk_toward(Direction::Backward);
+                    return;
+                }
+            }
+        }
+
+        match warrior.check() {
+            Tile::Unit(UnitType::Captive) => warrior.rescue(),
+            Tile::Unit(_) => warrior.attack(),
+            _ if level >= 8 && enemy_in_sight(warrior) => warrior.shoot(),
+            _ if level >= 3 && health < REST_BELOW && !hurt && !warrior.is(Effect::Poisoned) => {
+                warrior.rest()
+            }
+            _ if level >= 6
+                && hurt
+                && health < RETREAT_BELOW
+                && warrior.check_toward(Direction::Backward) == Tile::Empty =>
+            {
+                warrior.walk_toward(Direction::Backward)
+            }
+            _ => warrior.walk(),
+        }
+    }
+}
+
+// whether the first unit the warrior can see ahead is an enemy
+fn enemy_in_sight(warrior: &Warrior) -> bool {
+    let armed = warrior.equipment().bow.is_some_and(|bow| bow.has_ammo());
+    let first = warrior.look().iter().find_map(|tile| match tile {
+        Tile::Unit(unit_type) => Some(*unit_type),
+        _ => None,
+    });
+    armed && first.is_some_and(|unit_type| unit_type != UnitType::Captive)
+}
+
+// a small pseudo-random number generator (SplitMix64), so that a seed builds
+// the same floor everywhere
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // a number from 0 up to (but not including) `n`
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    fn percent(&mut self, chance: usize) -> bool {
+        self.below(100) < chance
+    }
+}
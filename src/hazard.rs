@@ -0,0 +1,28 @@
+//! contains types that represent traps and other hazardous tiles
+
+/// Hazards lie in wait on otherwise empty tiles of a floor. They are handled
+/// by the [`hazard_system`](crate::engine::systems::hazard_system) after the
+/// warrior has taken their turn, and can only be detected by a warrior who
+/// has learned to *feel* them (see [`Warrior`](crate::warrior::Warrior)).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Hazard {
+    /// deals 4 damage to the warrior when walked onto, and slows them for
+    /// 2 turns
+    Spikes,
+    /// poisons the warrior when walked onto, dealing 1 damage per turn for
+    /// 3 turns
+    PoisonPool,
+    /// triggers an arrow trap when walked onto, dealing 3 damage
+    PressurePlate,
+}
+
+impl Hazard {
+    /// A character (`&str` for convenience) representation of the hazard
+    pub fn draw(self) -> &'static str {
+        match self {
+            Hazard::Spikes => "^",
+            Hazard::PoisonPool => "~",
+            Hazard::PressurePlate => "_",
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! contains the index of every warrior in a `rustwarrior` directory
+
+use std::{fs, io, path::Path};
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::profile::Profile;
+
+/// The file in the `rustwarrior` directory where its warriors are indexed.
+pub const INDEX_PATH: &str = "profiles.toml";
+
+/// A warrior in a [`ProfileIndex`].
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct IndexEntry {
+    /// The name of the warrior
+    pub name: String,
+    /// The warrior's game directory, relative to the `rustwarrior` directory
+    pub directory: String,
+}
+
+/// The warriors set up in a `rustwarrior` directory (each in a game directory
+/// of its own), for players who keep several AIs at once.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct ProfileIndex {
+    /// The directory of the warrior that was switched to last
+    pub current: Option<String>,
+    pub warriors: Vec<IndexEntry>,
+}
+
+impl ProfileIndex {
+    /// Load the index of the `rustwarrior` directory at `directory`. Game
+    /// directories that are missing from the index (e.g. ones set up before
+    /// there was an index) are added, and ones that no longer exist are
+    /// dropped.
+    pub fn load(directory: &Path) -> io::Result<ProfileIndex> {
+        let path = directory.join(INDEX_PATH);
+        let mut index: ProfileIndex = if path.exists() {
+            let contents = fs::read_to_string(&path)?;
+            toml::from_str(&contents).map_err(io::Error::other)?
+        } else {
+            ProfileIndex::default()
+        };
+
+        index
+            .warriors
+            .retain(|warrior| directory.join(&warrior.directory).join(".profile").exists());
+        if let Some(current) = &index.current
+            && index.find(current).is_none()
+        {
+            index.current = None;
+        }
+
+        for entry in fs::read_dir(directory)? {
+            let player_dir = entry?.path();
+            let Some(name) = player_dir.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            if index
+                .warriors
+                .iter()
+                .any(|warrior| warrior.directory == name)
+            {
+                continue;
+            }
+            if let Ok(profile) = Profile::load(&player_dir.join(".profile")) {
+                index.add(&profile);
+            }
+        }
+
+        Ok(index)
+    }
+
+    /// Save the index in the `rustwarrior` directory at `directory`.
+    pub fn save(&self, directory: &Path) -> io::Result<()> {
+        let contents = toml::to_string(self).map_err(io::Error::other)?;
+        fs::write(directory.join(INDEX_PATH), contents)
+    }
+
+    /// Find a warrior by name (ignoring case) or by directory.
+    pub fn find(&self, name: &str) -> Option<&IndexEntry> {
+        self.warriors
+            .iter()
+            .find(|warrior| warrior.name.eq_ignore_ascii_case(name) || warrior.directory == name)
+    }
+
+    /// Add the warrior of `profile` to the index (if it is not in it yet).
+    pub fn add(&mut self, profile: &Profile) {
+        let directory = profile.directory();
+        if self
+            .warriors
+            .iter()
+            .all(|warrior| warrior.directory != directory)
+        {
+            self.warriors.push(IndexEntry {
+                name: profile.name.clone(),
+                directory,
+            });
+            self.warriors.sort_by(|a, b| a.name.cmp(&b.name));
+        }
+    }
+
+    /// Remove the warrior in `directory` from the index.
+    pub fn remove(&mut self, directory: &str) {
+        self.warriors
+            .retain(|warrior| warrior.directory != directory);
+        if self.current.as_deref() == Some(directory) {
+            self.current = None;
+        }
+    }
+}
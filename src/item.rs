@@ -0,0 +1,31 @@
+//! contains types that represent items which can be found on a floor
+
+/// Items lie on the floor until the [`Warrior`](crate::warrior::Warrior)
+/// walks onto them, after which they are kept in the warrior's inventory
+/// until used with [`use_item`](crate::warrior::Warrior::use_item).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Item {
+    /// restores 8 HP when used
+    HealthPotion,
+    /// adds 5 arrows to the warrior's quiver when used
+    ArrowBundle,
+    /// opens a locked door next to the warrior when used
+    Key,
+}
+
+/// The HP restored by drinking a health potion.
+pub const HEALTH_POTION_HP: i32 = 8;
+
+/// The number of arrows in an arrow bundle.
+pub const ARROW_BUNDLE_AMMO: i32 = 5;
+
+impl Item {
+    /// A character (`&str` for convenience) representation of the item
+    pub fn draw(self) -> &'static str {
+        match self {
+            Item::HealthPotion => "!",
+            Item::ArrowBundle => "}",
+            Item::Key => "k",
+        }
+    }
+}
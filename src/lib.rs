@@ -20,12 +20,27 @@
 //! `cargo run` like in any other Rust project.
 
 pub mod actions;
+pub mod bundle;
+pub mod cli;
+pub mod daily;
+pub mod difficulty;
+pub mod effect;
 pub mod engine;
+pub mod equipment;
 pub mod floor;
 pub mod game;
+pub mod gauntlet;
+pub mod generator;
+pub mod hazard;
+pub mod index;
+pub mod item;
+pub mod objective;
 pub mod player;
 pub mod profile;
+pub mod replay;
 pub mod starter;
+pub mod testing;
+pub mod tournament;
 pub mod ui;
 pub mod unit;
 pub mod warrior;
@@ -33,6 +48,8 @@ pub mod warrior;
 pub use actions::Direction;
 pub use floor::Tile;
 pub use game::Game;
+pub use hazard::Hazard;
+pub use item::Item;
 pub use player::Player;
 pub use unit::UnitType;
 pub use warrior::Warrior;
@@ -1,7 +1,11 @@
-use std::io;
+use std::{env, process};
 
-use rust_warrior::starter;
+use rust_warrior::cli;
 
-fn main() -> io::Result<()> {
-    starter::generate()
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if let Err(e) = cli::run(&args) {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
 }
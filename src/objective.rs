@@ -0,0 +1,49 @@
+//! contains types that represent the goals of a level
+
+use std::fmt;
+
+use crate::engine::outcome::Outcome;
+
+/// Every level is completed by reaching the stairs. A
+/// [`Floor`](crate::floor::Floor) may also declare `Objective`s which must
+/// all be met by the time the warrior gets there.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Objective {
+    /// every captive on the floor has been rescued
+    RescueAllCaptives,
+    /// every enemy on the floor has been defeated
+    DefeatAllEnemies,
+    /// no captive has been killed
+    LoseNoCaptives,
+    /// the stairs were reached within the given number of turns
+    WithinTurns(usize),
+    /// the warrior took less than the given amount of damage
+    DamageBelow(i32),
+}
+
+impl Objective {
+    /// Returns `true` if the objective was met by the given `outcome`.
+    pub fn is_met(&self, outcome: &Outcome) -> bool {
+        match *self {
+            Objective::RescueAllCaptives => {
+                outcome.captives_remaining == 0 && outcome.captives_lost == 0
+            }
+            Objective::DefeatAllEnemies => outcome.enemies_remaining == 0,
+            Objective::LoseNoCaptives => outcome.captives_lost == 0,
+            Objective::WithinTurns(turns) => outcome.turns <= turns,
+            Objective::DamageBelow(damage) => outcome.damage_taken < damage,
+        }
+    }
+}
+
+impl fmt::Display for Objective {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Objective::RescueAllCaptives => write!(f, "Rescue all captives"),
+            Objective::DefeatAllEnemies => write!(f, "Defeat all enemies"),
+            Objective::LoseNoCaptives => write!(f, "Do not harm any captives"),
+            Objective::WithinTurns(turns) => write!(f, "Reach the stairs within {} turns", turns),
+            Objective::DamageBelow(damage) => write!(f, "Take less than {} damage", damage),
+        }
+    }
+}
@@ -11,3 +11,8 @@ pub trait Player: Send + Sync {
     /// can instruct the Warrior to take.
     fn play_turn(&mut self, warrior: &Warrior);
 }
+
+/// Creates a fresh instance of a `Player`, e.g. `|| Box::new(MyPlayer)`.
+/// The engine asks for a generator rather than an instance so that each level
+/// (or warrior) starts from a clean slate.
+pub type PlayerGenerator = fn() -> Box<dyn Player + Send + Sync>;
@@ -1,50 +1,524 @@
 //! contains the struct for saving player name and current level
 
 use base64::prelude::*;
+use hmac::{Hmac, Mac};
 use serde_derive::{Deserialize, Serialize};
-use std::str;
+use sha2::Sha256;
+use std::{error, fmt, fs, io, path::Path, str};
+
+use crate::{daily, difficulty::Difficulty, engine::outcome::Outcome, floor::Floor};
+
+/// The version of the .profile format written by this version of
+/// rust-warrior. Profiles saved with an older version are migrated when they
+/// are loaded (see [`Profile::from_toml`]).
+pub const PROFILE_VERSION: u32 = 6;
+
+// the key that profiles and completion proofs are signed with, which can be
+// replaced by setting RUST_WARRIOR_PROFILE_KEY when building (e.g. to run a
+// leaderboard that only accepts profiles signed by its own build)
+const SIGNING_KEY: &str = match option_env!("RUST_WARRIOR_PROFILE_KEY") {
+    Some(key) => key,
+    None => "rust-warrior profile signing key",
+};
 
 /// The player's profile tracks their game progress. It is saved in .profile at
 /// the root of the player's generated project.
 #[derive(Deserialize, Serialize)]
 pub struct Profile {
+    /// The version of the .profile format (see [`PROFILE_VERSION`])
+    pub version: u32,
     /// The name the player has chosen
     pub name: String,
     /// The level of the player's warrior
     pub level: usize,
     /// Whether the player has successfully completed the final floor
     pub maximus_oxidus: bool,
+    /// How tough the tower is, chosen when the profile was created
+    pub difficulty: Difficulty,
+    /// The best completion of each level, with proof (see
+    /// [`Profile::unproven_levels`])
+    pub completions: Vec<Completion>,
+    /// The warrior's record on each level that has been attempted
+    pub history: Vec<LevelHistory>,
+    /// Whether a death sends the warrior back to Level 1 (see
+    /// [`Profile::fall`])
+    pub hardcore: bool,
+    /// The warrior's record on each level attempted in hardcore mode, kept
+    /// apart from `history`
+    pub hardcore_history: Vec<LevelHistory>,
+    /// The number of times the warrior has died in hardcore mode
+    pub hardcore_deaths: u32,
+    /// The highest level cleared in hardcore mode
+    pub hardcore_best: usize,
+    /// The warrior's record on each day's daily challenge (see
+    /// [`daily`](crate::daily))
+    pub daily_history: Vec<DailyHistory>,
+}
+
+/// A player's record on a single level, across every attempt.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct LevelHistory {
+    pub level: usize,
+    /// the number of times the level was played
+    pub attempts: u32,
+    /// the date (YYYY-MM-DD, local) the level was first cleared, if it has been
+    pub first_cleared: Option<String>,
+    /// the personal best score
+    pub best_score: Option<i32>,
+    /// the fewest turns taken to clear the level
+    pub fewest_turns: Option<usize>,
+    /// the most HP remaining when clearing the level
+    pub most_hp: Option<i32>,
+}
+
+/// A player's record on the daily challenge of a single day.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct DailyHistory {
+    /// the day of the challenge (YYYY-MM-DD, local)
+    pub date: String,
+    /// the number of times the challenge was played that day
+    pub attempts: u32,
+    /// the best score, if the challenge was cleared
+    pub best_score: Option<i32>,
+}
+
+/// A record of a level being completed, which can be audited (see
+/// [`Completion::verify`]).
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Completion {
+    pub level: usize,
+    /// the points earned (see
+    /// [`Outcome::score`](crate::engine::outcome::Outcome::score))
+    pub score: i32,
+    /// the number of turns that were played
+    pub turns: usize,
+    /// a keyed digest of the player's name, the level, the score, and the
+    /// number of turns
+    pub digest: String,
+}
+
+impl Completion {
+    /// Prove that the player named `name` completed `level`.
+    pub fn new(name: &str, level: usize, score: i32, turns: usize) -> Completion {
+        Completion {
+            level,
+            score,
+            turns,
+            digest: sign(&format!("{}:{}:{}:{}", name, level, score, turns)),
+        }
+    }
+
+    /// Whether this is a genuine completion by the player named `name`.
+    pub fn verify(&self, name: &str) -> bool {
+        *self == Completion::new(name, self.level, self.score, self.turns)
+    }
+}
+
+/// The reasons a .profile can fail to load.
+#[derive(Debug, PartialEq)]
+pub enum ProfileError {
+    /// There is no .profile (e.g. the game was not run from a game directory)
+    Missing,
+    /// The .profile could not be read
+    Io(String),
+    /// The .profile is not valid TOML, or is missing required fields
+    Invalid(String),
+    /// The .profile was saved by a newer version of rust-warrior
+    UnsupportedVersion(u32),
+    /// The .profile does not match its signature, i.e. it was edited by hand
+    Tampered,
+}
+
+impl fmt::Display for ProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ProfileError::Missing => write!(f, "no .profile found"),
+            ProfileError::Io(e) => write!(f, "failed to read .profile: {}", e),
+            ProfileError::Invalid(e) => write!(f, "failed to parse .profile: {}", e),
+            ProfileError::UnsupportedVersion(version) => write!(
+                f,
+                ".profile has version {}, but this version of rust-warrior only supports up to version {}",
+                version, PROFILE_VERSION
+            ),
+            ProfileError::Tampered => write!(f, "profile was modified outside of the game"),
+        }
+    }
 }
 
+impl error::Error for ProfileError {}
+
 impl Profile {
     /// create new Profile for player with given `name`
     pub fn new(name: String) -> Profile {
         Profile {
+            version: PROFILE_VERSION,
             name,
             level: 1,
             maximus_oxidus: false,
+            difficulty: Difficulty::default(),
+            completions: Vec::new(),
+            history: Vec::new(),
+            hardcore: false,
+            hardcore_history: Vec::new(),
+            hardcore_deaths: 0,
+            hardcore_best: 0,
+            daily_history: Vec::new(),
+        }
+    }
+
+    /// Add an attempt at `level` to the warrior's history (or hardcore
+    /// history, in hardcore mode). If the level was cleared, it is also
+    /// recorded as a [`Completion`]. Returns a description of each personal
+    /// best that was set.
+    pub fn record_attempt(&mut self, level: usize, outcome: &Outcome) -> Vec<String> {
+        let histories = if self.hardcore {
+            &mut self.hardcore_history
+        } else {
+            &mut self.history
+        };
+        let i = match histories.iter().position(|h| h.level == level) {
+            Some(i) => i,
+            None => {
+                histories.push(LevelHistory {
+                    level,
+                    ..LevelHistory::default()
+                });
+                histories.sort_by_key(|h| h.level);
+                histories.iter().position(|h| h.level == level).unwrap()
+            }
+        };
+        let history = &mut histories[i];
+        history.attempts += 1;
+        if !outcome.is_success() {
+            return Vec::new();
+        }
+
+        let mut bests = Vec::new();
+        if history.first_cleared.is_none() {
+            history.first_cleared = Some(daily::today());
+        }
+        let score = outcome.score();
+        if let Some(was) = improve(&mut history.best_score, score, |a, b| a > b) {
+            bests.push(format!("score of {} (was {})", score, was));
         }
+        let turns = outcome.turns;
+        if let Some(was) = improve(&mut history.fewest_turns, turns, |a, b| a < b) {
+            bests.push(format!("{} turns (was {})", turns, was));
+        }
+        let (hp, _) = outcome.hp;
+        if let Some(was) = improve(&mut history.most_hp, hp, |a, b| a > b) {
+            bests.push(format!("{} HP remaining (was {})", hp, was));
+        }
+
+        if self.hardcore {
+            self.hardcore_best = self.hardcore_best.max(level);
+        }
+        self.complete_level(level, score, turns);
+        bests
+    }
+
+    /// Add an attempt at the daily challenge of `date` to the warrior's
+    /// daily history. Returns `true` if the challenge was cleared with the
+    /// best score of the day so far.
+    pub fn record_daily(&mut self, date: &str, outcome: &Outcome) -> bool {
+        let i = match self.daily_history.iter().position(|h| h.date == date) {
+            Some(i) => i,
+            None => {
+                self.daily_history.push(DailyHistory {
+                    date: String::from(date),
+                    ..DailyHistory::default()
+                });
+                self.daily_history.len() - 1
+            }
+        };
+        let history = &mut self.daily_history[i];
+        history.attempts += 1;
+        let score = outcome.score();
+        if !outcome.is_success() || history.best_score.is_some_and(|best| best >= score) {
+            return false;
+        }
+        history.best_score = Some(score);
+        true
     }
 
     pub fn increment_level(&mut self) {
         self.level += 1;
     }
 
-    /// load Profile from base64 encoded TOML String
-    pub fn from_toml(contents: &str) -> Profile {
-        let err = "failed to parse .profile";
-        let bytes = BASE64_STANDARD.decode(contents).expect(err);
-        let decoded = str::from_utf8(&bytes).expect(err);
-        toml::from_str(decoded).expect(err)
+    /// In hardcore mode, a death sends the warrior back to Level 1 (and costs
+    /// them the title of Maximus Oxidus).
+    pub fn fall(&mut self) {
+        self.hardcore_deaths += 1;
+        self.level = 1;
+        self.maximus_oxidus = false;
+    }
+
+    /// Record that `level` was completed with `score` in `turns` turns,
+    /// unless it has already been completed with a better score.
+    pub fn complete_level(&mut self, level: usize, score: i32, turns: usize) {
+        let completion = Completion::new(&self.name, level, score, turns);
+        match self.completions.iter_mut().find(|c| c.level == level) {
+            Some(best) if best.score >= score => {}
+            Some(best) => *best = completion,
+            None => {
+                self.completions.push(completion);
+                self.completions.sort_by_key(|c| c.level);
+            }
+        }
     }
 
-    /// convert Profile to base64 encoded TOML String
+    /// The levels the warrior has climbed past without a genuine
+    /// [`Completion`] to show for it (e.g. every level of a profile from
+    /// before completions were recorded).
+    pub fn unproven_levels(&self) -> Vec<usize> {
+        let climbed = if self.maximus_oxidus {
+            self.level
+        } else {
+            self.level - 1
+        };
+        (1..=climbed)
+            .filter(|level| {
+                !self
+                    .completions
+                    .iter()
+                    .any(|c| c.level == *level && c.verify(&self.name))
+            })
+            .collect()
+    }
+
+    /// The highest level the warrior can be put back on (e.g. by
+    /// `rust-warrior reset`): the level after the last of the levels they
+    /// have proven, one after another, from Level 1.
+    pub fn proven_level(&self) -> usize {
+        let mut level = 1;
+        while level < Floor::count()
+            && self
+                .completions
+                .iter()
+                .any(|c| c.level == level && c.verify(&self.name))
+        {
+            level += 1;
+        }
+        level
+    }
+
+    /// A new profile for the warrior named `name`, to replace the broken
+    /// .profile at `path`. Any genuine completions by the warrior that can
+    /// still be read from it are kept, so that the levels they prove can be
+    /// picked up again (see [`Profile::proven_level`]).
+    pub fn recover(name: String, path: &Path) -> Profile {
+        let mut profile = Profile::new(name);
+        let completions = fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| parse_table(&contents).ok())
+            .and_then(|mut table| table.remove("completions"))
+            .and_then(|completions| completions.try_into::<Vec<Completion>>().ok())
+            .unwrap_or_default();
+        profile.completions = completions
+            .into_iter()
+            .filter(|c| c.verify(&profile.name))
+            .collect();
+        profile
+    }
+
+    /// load Profile from the .profile file at `path`
+    pub fn load(path: &Path) -> Result<Profile, ProfileError> {
+        let contents = fs::read_to_string(path).map_err(|e| match e.kind() {
+            io::ErrorKind::NotFound => ProfileError::Missing,
+            _ => ProfileError::Io(e.to_string()),
+        })?;
+        Profile::from_toml(&contents)
+    }
+
+    /// Load Profile from base64 encoded TOML String. Profiles saved by any
+    /// older version of rust-warrior (including plain TOML ones) are migrated
+    /// to the current [`PROFILE_VERSION`]. Signed profiles must match their
+    /// signature.
+    pub fn from_toml(contents: &str) -> Result<Profile, ProfileError> {
+        let mut table = parse_table(contents)?;
+        let signature = table.remove("signature");
+        let version = version(&table)?;
+        if version > PROFILE_VERSION {
+            return Err(ProfileError::UnsupportedVersion(version));
+        }
+        // the signature covers the profile as it was saved, so it is checked
+        // before migrating; profiles from before signing cannot be checked,
+        // so they must at least make sense, and every level they claim is
+        // left unproven (they have no completions)
+        if version >= 2 {
+            if signature.as_ref().and_then(toml::Value::as_str) != Some(&signature_of(&table)) {
+                return Err(ProfileError::Tampered);
+            }
+        } else {
+            check_unsigned(&table)?;
+        }
+        toml::Value::Table(migrate(table)?)
+            .try_into()
+            .map_err(|e: toml::de::Error| ProfileError::Invalid(e.to_string()))
+    }
+
+    /// convert Profile to base64 encoded TOML String, signed
     pub fn to_toml(&self) -> String {
-        let profile_toml = toml::to_string(&self).unwrap();
+        let mut table = toml::Table::try_from(self).unwrap();
+        let signature = signature_of(&table);
+        table.insert(String::from("signature"), toml::Value::String(signature));
+        let profile_toml = toml::to_string(&table).unwrap();
         BASE64_STANDARD.encode(profile_toml.as_bytes())
     }
 
+    /// The name of the warrior's game directory: their name in lowercase,
+    /// with every run of characters other than `a-z` and `0-9` replaced by
+    /// a single `-` (e.g. `mr-bot-2` for "Mr. Bot 2").
     pub fn directory(&self) -> String {
-        self.name.to_lowercase().replace(r"[^a-z0-9]+", "-")
+        slug(&self.name)
+    }
+}
+
+// `name` in lowercase, with runs of anything but letters and digits turned
+// into single dashes (and none at either end), or "warrior" if nothing is left
+pub(crate) fn slug(name: &str) -> String {
+    let mut slug = String::new();
+    for c in name.to_lowercase().chars() {
+        if c.is_ascii_lowercase() || c.is_ascii_digit() {
+            slug.push(c);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug = slug.trim_end_matches('-');
+    if slug.is_empty() {
+        String::from("warrior")
+    } else {
+        slug.to_string()
+    }
+}
+
+// the TOML table of a profile saved as `contents` (base64 encoded or not)
+fn parse_table(contents: &str) -> Result<toml::Table, ProfileError> {
+    let decoded = BASE64_STANDARD
+        .decode(contents.trim())
+        .ok()
+        .and_then(|bytes| String::from_utf8(bytes).ok());
+    let profile_toml = decoded.as_deref().unwrap_or(contents);
+    toml::from_str(profile_toml).map_err(|e| ProfileError::Invalid(e.to_string()))
+}
+
+// an unsigned profile is only loaded if its level is a level of the tower,
+// and it only claims the title Maximus Oxidus at the top
+fn check_unsigned(table: &toml::Table) -> Result<(), ProfileError> {
+    let level = table.get("level").and_then(toml::Value::as_integer);
+    let maximus_oxidus = table.get("maximus_oxidus").and_then(toml::Value::as_bool);
+    let top = Floor::count() as i64;
+    match (level, maximus_oxidus) {
+        (Some(level), _) if !(1..=top).contains(&level) => Err(ProfileError::Invalid(format!(
+            "level {} is not a level of the tower",
+            level
+        ))),
+        (Some(level), Some(true)) if level != top => Err(ProfileError::Invalid(format!(
+            "Maximus Oxidus cannot be on Level {}",
+            level
+        ))),
+        _ => Ok(()),
+    }
+}
+
+// a keyed digest of everything in a profile's `table` (which, unlike the
+// profile itself, serializes the same way whichever version saved it)
+fn signature_of(table: &toml::Table) -> String {
+    sign(&toml::to_string(table).unwrap())
+}
+
+// the HMAC-SHA256 of `message`, in hex
+fn sign(message: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(SIGNING_KEY.as_bytes())
+        .expect("HMAC accepts keys of any length");
+    mac.update(message.as_bytes());
+    mac.finalize()
+        .into_bytes()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+// keep `value` as the `best` if it is better, returning the previous best
+// that it beat (if there was one)
+fn improve<T: Copy>(best: &mut Option<T>, value: T, better: fn(T, T) -> bool) -> Option<T> {
+    match *best {
+        Some(was) if better(value, was) => {
+            *best = Some(value);
+            Some(was)
+        }
+        Some(_) => None,
+        None => {
+            *best = Some(value);
+            None
+        }
+    }
+}
+
+// the version of a profile that has not been migrated yet
+fn version(table: &toml::Table) -> Result<u32, ProfileError> {
+    match table.get("version") {
+        None => Ok(0),
+        Some(toml::Value::Integer(version)) => u32::try_from(*version)
+            .map_err(|_| ProfileError::Invalid(format!("invalid version {}", version))),
+        Some(_) => Err(ProfileError::Invalid(String::from(
+            "version must be an integer",
+        ))),
+    }
+}
+
+// bring a profile of any older version up to the current version, one
+// version at a time
+fn migrate(mut table: toml::Table) -> Result<toml::Table, ProfileError> {
+    loop {
+        let version = version(&table)?;
+        match version {
+            // profiles from before versioning may not have tracked whether
+            // the tower was completed
+            0 => {
+                table
+                    .entry("maximus_oxidus")
+                    .or_insert(toml::Value::Boolean(false));
+            }
+            // profiles from before signing have no completions
+            1 => {
+                table.insert(String::from("completions"), toml::Value::Array(Vec::new()));
+            }
+            // nor any history
+            2 => {
+                table.insert(String::from("history"), toml::Value::Array(Vec::new()));
+            }
+            // profiles from before difficulties were played on Normal
+            3 => {
+                table.insert(
+                    String::from("difficulty"),
+                    toml::Value::String(Difficulty::Normal.to_string()),
+                );
+            }
+            // and not in hardcore mode
+            4 => {
+                table.insert(String::from("hardcore"), toml::Value::Boolean(false));
+                table.insert(
+                    String::from("hardcore_history"),
+                    toml::Value::Array(Vec::new()),
+                );
+                table.insert(String::from("hardcore_deaths"), toml::Value::Integer(0));
+                table.insert(String::from("hardcore_best"), toml::Value::Integer(0));
+            }
+            // nor had they taken on any daily challenges
+            5 => {
+                table.insert(
+                    String::from("daily_history"),
+                    toml::Value::Array(Vec::new()),
+                );
+            }
+            PROFILE_VERSION => return Ok(table),
+            _ => return Err(ProfileError::UnsupportedVersion(version)),
+        }
+        table.insert(
+            String::from("version"),
+            toml::Value::Integer(i64::from(version + 1)),
+        );
     }
 }
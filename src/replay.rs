@@ -0,0 +1,53 @@
+//! contains the recording of a level, so that it can be watched again
+
+use std::{fs, path::Path, thread, time};
+
+use serde_derive::{Deserialize, Serialize};
+
+/// The file in the player's game directory where their latest attempt at a
+/// level is saved.
+pub const REPLAY_PATH: &str = "replay.toml";
+
+/// A recording of an attempt at a level: everything that was drawn, turn by
+/// turn. [`Game`](crate::game::Game) saves one after every attempt, which can
+/// be watched with `rust-warrior replay replay.toml`.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Replay {
+    /// The name of the player's warrior
+    pub player: String,
+    /// The level that was played
+    pub level: usize,
+    /// The points earned (see
+    /// [`Outcome::score`](crate::engine::outcome::Outcome::score))
+    #[serde(default)]
+    pub score: i32,
+    /// The lines drawn on each turn (the first is the floor before any turn)
+    pub frames: Vec<Vec<String>>,
+}
+
+impl Replay {
+    /// load a Replay from a TOML file at `path`
+    pub fn load(path: &Path) -> Result<Replay, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+        toml::from_str(&contents).map_err(|e| format!("failed to parse {}: {}", path.display(), e))
+    }
+
+    /// save the Replay as a TOML file at `path`
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let contents =
+            toml::to_string(self).map_err(|e| format!("failed to serialize replay: {}", e))?;
+        fs::write(path, contents).map_err(|e| format!("failed to write {}: {}", path.display(), e))
+    }
+
+    /// Print every frame, waiting `delay` milliseconds in between.
+    pub fn play(&self, delay: u64) {
+        println!("Replaying Level {} played by {}", self.level, self.player);
+        for frame in &self.frames {
+            for line in frame {
+                println!("{}", line);
+            }
+            thread::sleep(time::Duration::from_millis(delay));
+        }
+    }
+}
@@ -2,12 +2,40 @@
 
 use std::fs;
 use std::io;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process;
 
-use crate::{profile::Profile, ui};
-
-fn generate_readme(level: usize, player: &str) -> String {
+use crate::{
+    difficulty::Difficulty,
+    floor::Floor,
+    index::ProfileIndex,
+    profile::Profile,
+    ui,
+    unit::{Unit, UnitType},
+};
+
+pub(crate) fn generate_readme(level: usize, player: &str, difficulty: Difficulty) -> String {
+    // the stats of enemies and resting depend on the difficulty
+    let enemy_hp = |unit_type| {
+        let mut unit = Unit::new(unit_type, (0, 0));
+        difficulty.scale(&mut unit);
+        let (_, max) = unit.hp;
+        max
+    };
+    let sludge = enemy_hp(UnitType::Sludge);
+    let thick_sludge = enemy_hp(UnitType::ThickSludge);
+    let archer = enemy_hp(UnitType::Archer);
+    let wizard = enemy_hp(UnitType::Wizard);
+    let rest = difficulty.rest_percent();
+    let difficulty = match difficulty {
+        Difficulty::Normal => String::new(),
+        _ => format!(
+            "Difficulty: {} (enemies have {}% of their usual HP and ATK, and you have {} turns to reach the stairs)\n\n",
+            difficulty,
+            difficulty.enemy_percent(),
+            difficulty.turn_limit()
+        ),
+    };
     match level {
         1 => format!(
             "# Level 1
@@ -26,7 +54,7 @@ Tip: Call `warrior.walk()` in the `Player::play_turn` method.
   @ = {player} (20 HP)
 ```
 
-Warrior abilities: https://docs.rs/rust-warrior/latest/rust_warrior/warrior/struct.Warrior.html
+{difficulty}Warrior abilities: https://docs.rs/rust-warrior/latest/rust_warrior/warrior/struct.Warrior.html
 
 ----------
 
@@ -50,10 +78,10 @@ to see if there is anything in front of you or if it's `Empty`. Call
 
   > = Stairs
   @ = {player} (20 HP)
-  s = Sludge (12 HP)
+  s = Sludge ({sludge} HP)
 ```
 
-Warrior abilities: https://docs.rs/rust-warrior/latest/rust_warrior/warrior/struct.Warrior.html
+{difficulty}Warrior abilities: https://docs.rs/rust-warrior/latest/rust_warrior/warrior/struct.Warrior.html
 
 ----------
 
@@ -67,7 +95,9 @@ When you're ready, use `cargo run` to attempt this challenge.
 The air feels thicker than before. There must be a horde of sludge.
 
 Tip: Be careful not to die! Use `warrior.health()` to keep an eye on your health
-and `warrior.rest()` to earn 10% of max health back.
+and `warrior.rest()` to earn {rest}% of max health back. Sludge attacks are
+poisonous, and you cannot rest while poisoned. Add a `rust_warrior::effect::Effect`
+import and check `warrior.is(Effect::Poisoned)`.
 
 ```
  ---------
@@ -76,10 +106,10 @@ and `warrior.rest()` to earn 10% of max health back.
 
   > = Stairs
   @ = {player} (20 HP)
-  s = Sludge (12 HP)
+  s = Sludge ({sludge} HP)
 ```
 
-Warrior abilities: https://docs.rs/rust-warrior/latest/rust_warrior/warrior/struct.Warrior.html
+{difficulty}Warrior abilities: https://docs.rs/rust-warrior/latest/rust_warrior/warrior/struct.Warrior.html
 
 ----------
 
@@ -92,7 +122,8 @@ When you're ready, use `cargo run` to attempt this challenge.
 
 You can hear bow strings being stretched.
 
-Tip: No new abilities this time, but you must be careful not to rest while
+Tip: No new abilities this time, but you have found some leather armor, which
+absorbs 1 damage from every attack. You must be careful not to rest while
 taking damage. Add a `health` field to your `Player` struct and compare it on
 each turn to see if you're taking damage.
 
@@ -103,11 +134,11 @@ each turn to see if you're taking damage.
 
   > = Stairs
   @ = {player} (20 HP)
-  S = Thick Sludge (18 HP)
-  a = Archer (7 HP)
+  S = Thick Sludge ({thick_sludge} HP)
+  a = Archer ({archer} HP)
 ```
 
-Warrior abilities: https://docs.rs/rust-warrior/latest/rust_warrior/warrior/struct.Warrior.html
+{difficulty}Warrior abilities: https://docs.rs/rust-warrior/latest/rust_warrior/warrior/struct.Warrior.html
 
 ----------
 
@@ -132,11 +163,11 @@ if there is a captive (`Tile::Unit(UnitType::Captive)`). Call
   > = Stairs
   @ = {player} (20 HP)
   C = Captive (1 HP)
-  a = Archer (7 HP)
-  S = Thick Sludge (18 HP)
+  a = Archer ({archer} HP)
+  S = Thick Sludge ({thick_sludge} HP)
 ```
 
-Warrior abilities: https://docs.rs/rust-warrior/latest/rust_warrior/warrior/struct.Warrior.html
+{difficulty}Warrior abilities: https://docs.rs/rust-warrior/latest/rust_warrior/warrior/struct.Warrior.html
 
 ----------
 
@@ -155,7 +186,8 @@ The directional action methods are `warrior.walk_toward(Direction)`,
 `warrior.check_toward(Direction)`, `warrior.attack_toward(Direction)`,
 and `warrior.rescue_toward(Direction)`. Archer attacks have a limited range.
 Walk backward if you are taking damage from afar and don't have enough health
-to engage. Consider backing up until you've reached a `Tile::Wall`.
+to engage. Consider backing up until you've reached a `Tile::Wall`. You have
+also found a long sword, which deals 7 damage.
 
 ```
  ---------
@@ -165,11 +197,11 @@ to engage. Consider backing up until you've reached a `Tile::Wall`.
   > = Stairs
   @ = {player} (20 HP)
   C = Captive (1 HP)
-  S = Thick Sludge (18 HP)
-  a = Archer (7 HP)
+  S = Thick Sludge ({thick_sludge} HP)
+  a = Archer ({archer} HP)
 ```
 
-Warrior abilities: https://docs.rs/rust-warrior/latest/rust_warrior/warrior/struct.Warrior.html
+{difficulty}Warrior abilities: https://docs.rs/rust-warrior/latest/rust_warrior/warrior/struct.Warrior.html
 
 ----------
 
@@ -193,11 +225,11 @@ Tip: You are not as effective at attacking backward. Use
 
   > = Stairs
   @ = {player} (20 HP)
-  a = Archer (7 HP)
-  S = Thick Sludge (18 HP)
+  a = Archer ({archer} HP)
+  S = Thick Sludge ({thick_sludge} HP)
 ```
 
-Warrior abilities: https://docs.rs/rust-warrior/latest/rust_warrior/warrior/struct.Warrior.html
+{difficulty}Warrior abilities: https://docs.rs/rust-warrior/latest/rust_warrior/warrior/struct.Warrior.html
 
 ----------
 
@@ -213,7 +245,7 @@ found a bow and some arrows.
 
 Tip: Use `warrior.look` to determine your surroundings, and `warrior.shoot` to
 fire an arrow. Wizards are deadly but low in health. Kill them before they
-have time to attack.
+have time to attack. A wizard's bolt will stun you for a turn.
 
 ```
  -------
@@ -223,10 +255,10 @@ have time to attack.
   > = Stairs
   @ = {player} (20 HP)
   C = Captive (1 HP)
-  w = Wizard (3 HP)
+  w = Wizard ({wizard} HP)
 ```
 
-Warrior abilities: https://docs.rs/rust-warrior/latest/rust_warrior/warrior/struct.Warrior.html
+{difficulty}Warrior abilities: https://docs.rs/rust-warrior/latest/rust_warrior/warrior/struct.Warrior.html
 
 ----------
 
@@ -239,7 +271,10 @@ When you're ready, use `cargo run` to attempt this challenge.
 
 Time to hone your skills and apply all of the abilities that you have learned.
 
-Tip: Watch your back.
+Tip: Watch your back. You have traded up to chain mail and a long bow, which
+shoots (and lets you `look`) five tiles but only has 10 arrows. Check
+`warrior.equipment()` before you shoot, and pick up arrow bundles (`}}`) to
+refill your quiver.
 
 ```
  ------------
@@ -249,12 +284,12 @@ Tip: Watch your back.
   > = Stairs
   @ = {player} (20 HP)
   C = Captive (1 HP)
-  a = Archer (7 HP)
-  S = Thick Sludge (18 HP)
-  w = Wizard (3 HP)
+  a = Archer ({archer} HP)
+  S = Thick Sludge ({thick_sludge} HP)
+  w = Wizard ({wizard} HP)
 ```
 
-Warrior abilities: https://docs.rs/rust-warrior/latest/rust_warrior/warrior/struct.Warrior.html
+{difficulty}Warrior abilities: https://docs.rs/rust-warrior/latest/rust_warrior/warrior/struct.Warrior.html
 
 ----------
 
@@ -266,6 +301,19 @@ When you're ready, use `cargo run` to attempt this challenge.
     }
 }
 
+// the name of the player's struct: their name, without any characters
+// that cannot appear in a Rust identifier
+fn struct_name(player: &str) -> String {
+    let name: String = player
+        .chars()
+        .filter(|c| c.is_alphanumeric() || *c == '_')
+        .collect();
+    match name.chars().next() {
+        Some(first) if !first.is_numeric() => name,
+        _ => format!("Warrior{}", name),
+    }
+}
+
 fn generate_main_rs(player: &str) -> String {
     format!(
         "use rust_warrior::{{Game, Player, Warrior}};
@@ -291,7 +339,49 @@ fn main() {{
     Game::play({player}::new_player);
 }}
 ",
-        player = player
+        player = struct_name(player)
+    )
+}
+
+fn generate_levels_rs(profile: &Profile) -> String {
+    let unlocked = if profile.maximus_oxidus {
+        Floor::count()
+    } else {
+        profile.level
+    };
+    // levels are tested at the profile's difficulty, with the abilities
+    // learned so far
+    let tests: Vec<String> = (1..=unlocked)
+        .map(|level| {
+            format!(
+                "#[test]
+fn level_{level}() {{
+    testing::assert_beats_level_at({player}::new_player, {level}, Difficulty::{difficulty:?}, {unlocked});
+}}
+",
+                level = level,
+                player = struct_name(&profile.name),
+                difficulty = profile.difficulty,
+                unlocked = unlocked
+            )
+        })
+        .collect();
+    format!(
+        "// This file is generated by rust-warrior and is rewritten each time you
+// reach a new level, so any changes to it will be lost. Use `cargo test` to
+// check that {player} can beat every level unlocked so far (including the
+// one you are working on). Other tests can be added in files of their own,
+// see https://docs.rs/rust-warrior/latest/rust_warrior/testing/index.html
+
+#![allow(dead_code)]
+
+include!(\"../src/main.rs\");
+
+use rust_warrior::{{difficulty::Difficulty, testing}};
+
+{tests}",
+        player = &profile.name,
+        tests = tests.join("\n")
     )
 }
 
@@ -300,15 +390,63 @@ fn generate_cargo_toml(name: &str) -> String {
         "[package]
 name = \"rustwarrior-{name}\"
 version = \"0.1.0\"
-edition = \"2021\"
+edition = \"2024\"
 
 [dependencies]
-rust-warrior = \"0.14.1\"
+rust-warrior = \"{version}\"
 ",
-        name = name
+        name = name,
+        version = env!("CARGO_PKG_VERSION")
     )
 }
 
+// point the rust-warrior dependency of a player's Cargo.toml at this version,
+// keeping everything else as the player left it (returns `None` if there is
+// no rust-warrior dependency to update)
+fn upgrade_cargo_toml(contents: &str) -> Option<String> {
+    let version = env!("CARGO_PKG_VERSION");
+    let mut found = false;
+    let mut lines: Vec<String> = contents
+        .lines()
+        .map(|line| {
+            let Some(value) = line
+                .trim_start()
+                .strip_prefix("rust-warrior")
+                .and_then(|rest| rest.trim_start().strip_prefix('='))
+            else {
+                return String::from(line);
+            };
+            found = true;
+            // e.g. `"0.14.1"` or `{ version = "0.14.1", features = [...] }`,
+            // but a `path` or `git` dependency has no version to update
+            let search = if value.trim_start().starts_with('"') {
+                Some(0)
+            } else {
+                value.find("version")
+            };
+            let start = search.and_then(|i| value[i..].find('"').map(|j| i + j + 1));
+            let end = start.and_then(|i| value[i..].find('"').map(|j| i + j));
+            match (start, end) {
+                (Some(start), Some(end)) => format!(
+                    "{}{}{}{}",
+                    &line[..line.len() - value.len()],
+                    &value[..start],
+                    version,
+                    &value[end..]
+                ),
+                _ => String::from(line),
+            }
+        })
+        .collect();
+    if !found {
+        return None;
+    }
+    if contents.ends_with('\n') {
+        lines.push(String::new());
+    }
+    Some(lines.join("\n"))
+}
+
 /// Set up a new game directory and player profile
 ///
 /// Creates a `rustwarrior` directory if one does not exist yet.
@@ -316,8 +454,13 @@ rust-warrior = \"0.14.1\"
 /// with the following contents:
 ///
 /// * `src/main.rs`
+/// * `tests/levels.rs`
 /// * `Cargo.toml`
 /// * `.profile`
+/// * `README.md`
+///
+/// The new warrior is also added to the `rustwarrior` directory's
+/// [`ProfileIndex`](crate::index::ProfileIndex).
 ///
 /// From there, the newly generated crate can be used to start level one.
 pub fn generate() -> io::Result<()> {
@@ -325,7 +468,7 @@ pub fn generate() -> io::Result<()> {
 
     create_game_directory()?;
     let mut profile = create_profile();
-    create_game_files(&mut profile)?;
+    create_game_files(&mut profile, Path::new("rustwarrior"))?;
 
     println!(
         "Game files have been generated. See rustwarrior/{}/README.md for instructions.",
@@ -335,6 +478,52 @@ pub fn generate() -> io::Result<()> {
     Ok(())
 }
 
+/// Like `generate`, but without prompting: the player directory for the
+/// warrior of a new `profile` is created inside `directory` (which is created
+/// if it does not exist yet). Returns the path of the player directory.
+pub fn generate_in(mut profile: Profile, directory: &Path) -> io::Result<PathBuf> {
+    fs::create_dir_all(directory)?;
+    create_game_files(&mut profile, directory)
+}
+
+/// Bring an existing game directory up to date with this version of
+/// rust-warrior. The `rust-warrior` dependency in Cargo.toml is set to this
+/// version, .profile is migrated to the current
+/// [`PROFILE_VERSION`](crate::profile::PROFILE_VERSION), and README.md (and
+/// tests/levels.rs) are regenerated for the player's current level.
+/// The player's src/main.rs is left untouched.
+pub fn upgrade(directory: Option<&Path>) -> io::Result<Profile> {
+    let player_dir = directory.unwrap_or_else(|| Path::new("."));
+
+    let cargo_toml = player_dir.join("Cargo.toml");
+    let contents = fs::read_to_string(&cargo_toml)?;
+    let upgraded = upgrade_cargo_toml(&contents).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            "Cargo.toml does not depend on rust-warrior",
+        )
+    })?;
+    let profile = Profile::load(&player_dir.join(".profile")).map_err(io::Error::other)?;
+    fs::write(cargo_toml, upgraded)?;
+
+    write_profile(&profile, Some(player_dir))?;
+    write_readme(&profile, profile.level, Some(player_dir))?;
+
+    Ok(profile)
+}
+
+/// Like `generate_in`, but the new warrior named `name` starts at the same
+/// level (and difficulty and mode) as the warrior of `source` (their history is not
+/// copied, though).
+pub fn generate_clone(source: &Profile, name: &str, directory: &Path) -> io::Result<PathBuf> {
+    let mut profile = Profile::new(String::from(name));
+    profile.level = source.level;
+    profile.maximus_oxidus = source.maximus_oxidus;
+    profile.difficulty = source.difficulty;
+    profile.hardcore = source.hardcore;
+    create_game_files(&mut profile, directory)
+}
+
 fn create_game_directory() -> io::Result<()> {
     if Path::new("rustwarrior").exists() {
         return Ok(());
@@ -351,35 +540,50 @@ fn create_game_directory() -> io::Result<()> {
 }
 
 fn create_profile() -> Profile {
-    // TODO: menu to select difficulty
     let name = ui::request("Enter a name for your warrior: ");
-    Profile::new(name)
+    let mut profile = Profile::new(name);
+    profile.difficulty = ui::select_difficulty();
+    profile.hardcore = ui::ask("Play in hardcore mode, where a death sends you back to Level 1?");
+    profile
 }
 
-/// Write the README.md for the current level into the player's game directory
-pub fn write_readme(profile: &Profile, level: usize, directory: Option<&Path>) {
+/// Write the README.md for the current level into the player's game
+/// directory, along with tests/levels.rs (see `write_tests`)
+pub fn write_readme(profile: &Profile, level: usize, directory: Option<&Path>) -> io::Result<()> {
     let readme = match directory {
         Some(player_dir) => player_dir.join("README.md"),
         _ => Path::new("README.md").to_path_buf(),
     };
-    let contents = generate_readme(level, &profile.name);
-    fs::write(readme, contents)
-        .unwrap_or_else(|_| panic!("failed to generate level {} README.md", level));
+    let contents = generate_readme(level, &profile.name, profile.difficulty);
+    fs::write(readme, contents)?;
+    write_tests(profile, directory)
+}
+
+/// Write tests/levels.rs into the player's game directory, with a test
+/// asserting that their `Player` beats each level they have unlocked (see
+/// [`testing`](crate::testing))
+pub fn write_tests(profile: &Profile, directory: Option<&Path>) -> io::Result<()> {
+    let tests_dir = match directory {
+        Some(player_dir) => player_dir.join("tests"),
+        _ => Path::new("tests").to_path_buf(),
+    };
+    fs::create_dir_all(&tests_dir)?;
+    fs::write(tests_dir.join("levels.rs"), generate_levels_rs(profile))
 }
 
 /// Save the player's [`Profile`](crate::profile::Profile) to .profile in their
 /// game directory
-pub fn write_profile(profile: &Profile, directory: Option<&Path>) {
+pub fn write_profile(profile: &Profile, directory: Option<&Path>) -> io::Result<()> {
     let profile_toml = match directory {
         Some(player_dir) => player_dir.join(".profile"),
         _ => Path::new(".profile").to_path_buf(),
     };
-    fs::write(profile_toml, profile.to_toml()).expect("failed to write .profile");
+    fs::write(profile_toml, profile.to_toml())
 }
 
-fn create_game_files(profile: &mut Profile) -> io::Result<()> {
+fn create_game_files(profile: &mut Profile, directory: &Path) -> io::Result<PathBuf> {
     let profile_dir = &profile.directory();
-    let player_dir = Path::new("rustwarrior").join(profile_dir);
+    let player_dir = directory.join(profile_dir);
     fs::create_dir(&player_dir)?;
     let src_dir = player_dir.join("src");
     fs::create_dir(&src_dir)?;
@@ -388,8 +592,14 @@ fn create_game_files(profile: &mut Profile) -> io::Result<()> {
     let cargo_toml = player_dir.join("Cargo.toml");
     fs::write(cargo_toml, generate_cargo_toml(profile_dir))?;
 
-    write_profile(profile, Some(&player_dir));
-    write_readme(profile, 1, Some(&player_dir));
+    write_profile(profile, Some(&player_dir))?;
+    write_readme(profile, profile.level, Some(&player_dir))?;
 
-    Ok(())
+    // the new warrior becomes the current one
+    let mut index = ProfileIndex::load(directory)?;
+    index.add(profile);
+    index.current = Some(profile.directory());
+    index.save(directory)?;
+
+    Ok(player_dir)
 }
@@ -0,0 +1,328 @@
+//! contains helpers for testing a `Player` against the tower
+//!
+//! Levels are played headlessly (see
+//! [`engine::start_headless`](crate::engine::start_headless)), so these
+//! helpers never read a profile, prompt for input, print, or sleep between
+//! turns. They are meant to be used from your own `#[test]`s, e.g. in
+//! `tests/levels.rs` of your generated project:
+//!
+//! ```no_run
+//! use rust_warrior::{testing, Player, Warrior};
+//!
+//! struct Alice;
+//! impl Player for Alice {
+//!     fn play_turn(&mut self, warrior: &Warrior) {
+//!         warrior.walk();
+//!     }
+//! }
+//!
+//! #[test]
+//! fn beats_level_1() {
+//!     testing::assert_beats_level(|| Box::new(Alice), 1);
+//! }
+//! ```
+//!
+//! Single decisions of `play_turn` can be tested with a [`Scenario`]:
+//!
+//! ```
+//! # use rust_warrior::{actions::Action, testing::Scenario, Direction, Player, Tile, Warrior};
+//! # struct Alice;
+//! # impl Player for Alice {
+//! #     fn play_turn(&mut self, warrior: &Warrior) {
+//! #         match warrior.check() {
+//! #             Tile::Empty => warrior.walk(),
+//! #             _ => warrior.attack(),
+//! #         }
+//! #     }
+//! # }
+//! let warrior = Scenario::new("@s >").with_level(2).play(&mut Alice);
+//! assert_eq!(warrior.action(), Some(Action::Attack(Direction::Forward)));
+//! ```
+
+use crate::{
+    Player, Warrior,
+    actions::Direction,
+    difficulty::Difficulty,
+    effect::StatusEffect,
+    engine::{self, Mode, outcome::Outcome, systems::player::warrior_view},
+    equipment::Equipment,
+    floor::Floor,
+    gauntlet::Gauntlet,
+    item::Item,
+    player::PlayerGenerator,
+};
+
+// the name given to the warrior in test runs (it appears in failures)
+const TEST_PLAYER_NAME: &str = "Warrior";
+
+/// Plays the predefined floor for `level` with the abilities (and equipment)
+/// a warrior has on reaching it, and returns the [`Outcome`]. To play it with
+/// those of another level, see [`play_level_at`], or
+/// [`play_level_with_every_ability`].
+pub fn play_level(player_generator: PlayerGenerator, level: usize) -> Outcome {
+    play_level_on(player_generator, level, Difficulty::Normal)
+}
+
+/// Like [`play_level`], but the floor is played at `difficulty`.
+pub fn play_level_on(
+    player_generator: PlayerGenerator,
+    level: usize,
+    difficulty: Difficulty,
+) -> Outcome {
+    play_level_at(player_generator, level, difficulty, level)
+}
+
+/// Like [`play_level`], but the warrior has every ability (and the equipment
+/// of the top of the tower), as once Maximus Oxidus.
+pub fn play_level_with_every_ability(player_generator: PlayerGenerator, level: usize) -> Outcome {
+    play_level_at(player_generator, level, Difficulty::Normal, Floor::count())
+}
+
+/// Like [`play_level_on`], but the warrior has the abilities (and equipment)
+/// of a warrior at `warrior_level`, e.g. the level your profile has reached.
+pub fn play_level_at(
+    player_generator: PlayerGenerator,
+    level: usize,
+    difficulty: Difficulty,
+    warrior_level: usize,
+) -> Outcome {
+    let floor = Floor::load(level).with_difficulty(difficulty);
+    run_level_at(player_generator, warrior_level, floor)
+}
+
+/// Plays a custom `floor` with the abilities (and equipment) of a warrior on
+/// Level 1 and returns the [`Outcome`]. To play it with those of another
+/// level, see [`run_level_at`], or [`run_level_with_every_ability`].
+pub fn run_level(player_generator: PlayerGenerator, floor: Floor) -> Outcome {
+    run_level_at(player_generator, 1, floor)
+}
+
+/// Like [`run_level`], but the warrior has every ability (and the equipment
+/// of the top of the tower).
+pub fn run_level_with_every_ability(player_generator: PlayerGenerator, floor: Floor) -> Outcome {
+    run_level_at(player_generator, Floor::count(), floor)
+}
+
+/// Plays a custom `floor` with the abilities of a warrior at
+/// `warrior_level` and returns the [`Outcome`].
+pub fn run_level_at(
+    player_generator: PlayerGenerator,
+    warrior_level: usize,
+    floor: Floor,
+) -> Outcome {
+    engine::start_headless(
+        String::from(TEST_PLAYER_NAME),
+        warrior_level,
+        floor,
+        player_generator,
+    )
+}
+
+/// Climbs the tower headlessly in a [`Gauntlet`] at `difficulty`, until the
+/// warrior fails a floor or reaches the top. Every floor is played with
+/// every ability unlocked.
+pub fn play_gauntlet(player_generator: PlayerGenerator, difficulty: Difficulty) -> Gauntlet {
+    let mut gauntlet = Gauntlet::new(difficulty);
+    while let Some(floor) = gauntlet.next_floor() {
+        gauntlet.record(run_level_at(player_generator, Floor::count(), floor));
+    }
+    gauntlet
+}
+
+/// Plays `level` (see [`play_level`]) and panics unless the warrior reaches
+/// the stairs and meets every objective. Returns the [`Outcome`] for further
+/// assertions.
+pub fn assert_beats_level(player_generator: PlayerGenerator, level: usize) -> Outcome {
+    let outcome = play_level(player_generator, level);
+    assert_success(&outcome, &format!("Level {}", level));
+    outcome
+}
+
+/// Like [`assert_beats_level`], but the floor is played at `difficulty`.
+pub fn assert_beats_level_on(
+    player_generator: PlayerGenerator,
+    level: usize,
+    difficulty: Difficulty,
+) -> Outcome {
+    let outcome = play_level_on(player_generator, level, difficulty);
+    assert_success(&outcome, &format!("Level {} ({})", level, difficulty));
+    outcome
+}
+
+/// Like [`assert_beats_level_on`], but the warrior only has the abilities
+/// (and equipment) of a warrior at `warrior_level` (see [`play_level_at`]).
+pub fn assert_beats_level_at(
+    player_generator: PlayerGenerator,
+    level: usize,
+    difficulty: Difficulty,
+    warrior_level: usize,
+) -> Outcome {
+    let outcome = play_level_at(player_generator, level, difficulty, warrior_level);
+    assert_success(&outcome, &format!("Level {} ({})", level, difficulty));
+    outcome
+}
+
+/// Plays a custom `floor` (see [`run_level`]) and panics unless the warrior
+/// reaches the stairs and meets every objective. Returns the [`Outcome`]
+/// for further assertions.
+pub fn assert_beats_floor(player_generator: PlayerGenerator, floor: Floor) -> Outcome {
+    let outcome = run_level(player_generator, floor);
+    assert_success(&outcome, "the floor");
+    outcome
+}
+
+/// Panics if the level took more than `turns` turns.
+pub fn assert_turns_at_most(outcome: &Outcome, turns: usize) {
+    assert!(
+        outcome.turns <= turns,
+        "expected at most {} turns, but {} were played",
+        turns,
+        outcome.turns
+    );
+}
+
+/// Panics if the warrior ended the level with less than `hp` HP left.
+pub fn assert_hp_at_least(outcome: &Outcome, hp: i32) {
+    let (remaining, _) = outcome.hp;
+    assert!(
+        remaining >= hp,
+        "expected at least {} HP left, but {} HP were left",
+        hp,
+        remaining
+    );
+}
+
+/// Panics unless exactly `captives` captives were rescued.
+pub fn assert_captives_rescued(outcome: &Outcome, captives: usize) {
+    assert_eq!(
+        outcome.captives_rescued, captives,
+        "expected {} captive(s) rescued, but {} were",
+        captives, outcome.captives_rescued
+    );
+}
+
+/// Builds the [`Warrior`] that `play_turn` would be handed on a floor drawn
+/// as ASCII (see [`Floor::parse`](crate::floor::Floor::parse)), exactly as
+/// the engine would build it. The snippet must contain a single warrior (`@`).
+///
+/// The warrior has every ability unlocked unless `with_level` says
+/// otherwise, and starts with full health facing forward (east).
+pub struct Scenario {
+    floor: Floor,
+    level: usize,
+    health: Option<i32>,
+    facing: Option<Direction>,
+    equipment: Option<Equipment>,
+    inventory: Vec<Item>,
+    effects: Vec<StatusEffect>,
+}
+
+impl Scenario {
+    /// Create a scenario from an ASCII `snippet` of the floor, e.g. `"S@ a>"`.
+    pub fn new(snippet: &str) -> Scenario {
+        Scenario {
+            floor: Floor::parse(snippet),
+            level: Floor::count(),
+            health: None,
+            facing: None,
+            equipment: None,
+            inventory: Vec::new(),
+            effects: Vec::new(),
+        }
+    }
+
+    /// Give the warrior the abilities (and equipment) of `level`.
+    pub fn with_level(mut self, level: usize) -> Scenario {
+        self.level = level;
+        self
+    }
+
+    /// Set the warrior's current HP.
+    pub fn with_health(mut self, health: i32) -> Scenario {
+        self.health = Some(health);
+        self
+    }
+
+    /// Turn the warrior to face `facing`.
+    pub fn with_facing(mut self, facing: Direction) -> Scenario {
+        self.facing = Some(facing);
+        self
+    }
+
+    /// Replace the loadout earned at the warrior's level with `equipment`.
+    pub fn with_equipment(mut self, equipment: Equipment) -> Scenario {
+        self.equipment = Some(equipment);
+        self
+    }
+
+    /// Give the warrior the items in `inventory`.
+    pub fn with_inventory(mut self, inventory: Vec<Item>) -> Scenario {
+        self.inventory = inventory;
+        self
+    }
+
+    /// Afflict the warrior with the status `effects`.
+    pub fn with_effects(mut self, effects: Vec<StatusEffect>) -> Scenario {
+        self.effects = effects;
+        self
+    }
+
+    /// The warrior, ready to be handed to `play_turn`.
+    pub fn warrior(&self) -> Warrior {
+        let players = vec![(String::from(TEST_PLAYER_NAME), idle as PlayerGenerator)];
+        let mut world = engine::setup(self.level, self.floor.clone(), players, Mode::CoOp)
+            .unwrap_or_else(|e| panic!("invalid scenario: {}", e));
+
+        let unit = &mut world.warriors[0].unit;
+        if let Some(equipment) = self.equipment {
+            unit.equip(equipment);
+        }
+        if let Some(health) = self.health {
+            let (_, max) = unit.hp;
+            unit.hp = (health, max);
+        }
+        if self.facing.is_some() {
+            unit.facing = self.facing;
+        }
+        unit.inventory = self.inventory.clone();
+        unit.effects = self.effects.clone();
+
+        warrior_view(&world, 0)
+    }
+
+    /// Hand the warrior to `player` for a single turn, and return it so that
+    /// its `action` and `warnings` can be checked.
+    pub fn play(&self, player: &mut dyn Player) -> Warrior {
+        let warrior = self.warrior();
+        player.play_turn(&warrior);
+        warrior
+    }
+}
+
+// the scenario's world needs a player, though it never takes a turn
+struct Idle;
+
+impl Player for Idle {
+    fn play_turn(&mut self, _warrior: &Warrior) {}
+}
+
+fn idle() -> Box<dyn Player + Send + Sync> {
+    Box::new(Idle)
+}
+
+// panic with every reason the level was not beaten
+fn assert_success(outcome: &Outcome, level: &str) {
+    if outcome.is_success() {
+        return;
+    }
+    let mut reasons = Vec::new();
+    if let Some(failure) = &outcome.failure {
+        reasons.push(failure.clone());
+    }
+    for (objective, met) in &outcome.objectives {
+        if !met {
+            reasons.push(format!("Objective FAILED: {}", objective));
+        }
+    }
+    panic!("failed to beat {}: {}", level, reasons.join("; "));
+}
@@ -0,0 +1,176 @@
+//! contains a runner for pitting many players against the tower
+//!
+//! Every contestant's `Player` attempts every floor of the tower, with the
+//! abilities of a warrior at that level. The levels are played headlessly
+//! (see [`engine::start_headless`](crate::engine::start_headless)) and in
+//! parallel, and the results are ranked in a [`Leaderboard`].
+//!
+//! ```no_run
+//! use rust_warrior::{tournament, Player, Warrior};
+//!
+//! struct Alice;
+//! impl Player for Alice {
+//!     fn play_turn(&mut self, warrior: &Warrior) {
+//!         warrior.walk();
+//!     }
+//! }
+//!
+//! let leaderboard = tournament::run(vec![("Alice", || Box::new(Alice))]);
+//! println!("{}", leaderboard.to_markdown());
+//! ```
+
+use std::thread;
+
+use serde_derive::Serialize;
+
+use crate::{engine, floor::Floor, player::PlayerGenerator};
+
+/// How a contestant fared on a single level.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct LevelResult {
+    pub level: usize,
+    /// whether the warrior reached the stairs and met every objective
+    pub cleared: bool,
+    /// the points earned (see
+    /// [`Outcome::score`](crate::engine::outcome::Outcome::score))
+    pub score: i32,
+    /// the number of turns that were played
+    pub turns: usize,
+    /// why the level was not cleared, if it wasn't
+    pub failure: Option<String>,
+}
+
+/// A contestant's results across the whole tower.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Entry {
+    pub name: String,
+    pub levels_cleared: usize,
+    /// the total score of every level cleared
+    pub score: i32,
+    /// the total turns taken on every level cleared
+    pub turns: usize,
+    /// the levels cleared in a row from Level 1, i.e. how far the contestant
+    /// would climb in hardcore mode (where a death means starting over)
+    pub hardcore_levels: usize,
+    pub results: Vec<LevelResult>,
+}
+
+/// The contestants of a tournament, ranked by levels cleared, then total
+/// score, then fewest turns taken.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Leaderboard {
+    pub entries: Vec<Entry>,
+}
+
+impl Leaderboard {
+    /// Rank `entries` to form a leaderboard.
+    pub fn new(mut entries: Vec<Entry>) -> Leaderboard {
+        entries.sort_by(|a, b| {
+            b.levels_cleared
+                .cmp(&a.levels_cleared)
+                .then(b.score.cmp(&a.score))
+                .then(a.turns.cmp(&b.turns))
+                .then(a.name.cmp(&b.name))
+        });
+        Leaderboard { entries }
+    }
+
+    /// The leaderboard as a Markdown table.
+    pub fn to_markdown(&self) -> String {
+        let mut lines = vec![
+            String::from("| Rank | Player | Levels Cleared | Score | Turns | Hardcore |"),
+            String::from("| ---: | --- | ---: | ---: | ---: | ---: |"),
+        ];
+        for (i, entry) in self.entries.iter().enumerate() {
+            lines.push(format!(
+                "| {} | {} | {} | {} | {} | {} |",
+                i + 1,
+                entry.name,
+                entry.levels_cleared,
+                entry.score,
+                entry.turns,
+                entry.hardcore_levels
+            ));
+        }
+        lines.join("\n")
+    }
+
+    /// The leaderboard (including every level's result) as JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("failed to serialize leaderboard")
+    }
+}
+
+/// Runs every contestant (a name and a `Player` generator) across every
+/// floor of the tower, and ranks them. A `Player` that panics (e.g. by using
+/// an ability that has not been learned yet) fails that level.
+pub fn run(contestants: Vec<(&str, PlayerGenerator)>) -> Leaderboard {
+    let levels: Vec<usize> = (1..=Floor::count()).collect();
+
+    let entries = thread::scope(|scope| {
+        let handles: Vec<_> = contestants
+            .iter()
+            .map(|(name, player_generator)| {
+                let handles: Vec<_> = levels
+                    .iter()
+                    .map(|level| {
+                        let level = *level;
+                        let name = String::from(*name);
+                        let player_generator = *player_generator;
+                        let handle = scope.spawn(move || {
+                            engine::start_headless(
+                                name,
+                                level,
+                                Floor::load(level),
+                                player_generator,
+                            )
+                        });
+                        (level, handle)
+                    })
+                    .collect();
+                (name, handles)
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|(name, handles)| {
+                let results = handles
+                    .into_iter()
+                    .map(|(level, handle)| match handle.join() {
+                        Ok(outcome) => LevelResult {
+                            level,
+                            cleared: outcome.is_success(),
+                            score: outcome.score(),
+                            turns: outcome.turns,
+                            failure: outcome.failure.clone(),
+                        },
+                        Err(_) => LevelResult {
+                            level,
+                            cleared: false,
+                            score: 0,
+                            turns: 0,
+                            failure: Some(String::from("the player panicked")),
+                        },
+                    })
+                    .collect();
+                entry(name, results)
+            })
+            .collect()
+    });
+
+    Leaderboard::new(entries)
+}
+
+// total up a contestant's results
+fn entry(name: &str, results: Vec<LevelResult>) -> Entry {
+    let cleared: Vec<&LevelResult> = results.iter().filter(|result| result.cleared).collect();
+    Entry {
+        name: String::from(name),
+        levels_cleared: cleared.len(),
+        score: cleared.iter().map(|result| result.score).sum(),
+        turns: cleared.iter().map(|result| result.turns).sum(),
+        hardcore_levels: results.iter().take_while(|result| result.cleared).count(),
+        results,
+    }
+}
@@ -3,17 +3,38 @@
 use std::io;
 use std::io::prelude::*;
 
-pub fn select_level() -> usize {
+use crate::difficulty::Difficulty;
+
+/// Helper function for prompting the player to choose a level from 1 to
+/// `top`
+pub fn select_level(top: usize) -> usize {
     loop {
-        let response = request("Choose level to play [1-9] ");
+        let response = request(&format!("Choose level to play [1-{}] ", top));
         if let Ok(n) = response.parse::<usize>()
-            && (1..=9).contains(&n) {
-                break n;
-            }
+            && (1..=top).contains(&n)
+        {
+            break n;
+        }
         println!("{} is not a valid level.", response);
     }
 }
 
+/// Helper function for prompting the player to choose a
+/// [`Difficulty`](crate::difficulty::Difficulty) (Normal if they just press
+/// enter)
+pub fn select_difficulty() -> Difficulty {
+    loop {
+        let response = request("Choose a difficulty [easy/NORMAL/hard] ");
+        if response.is_empty() {
+            break Difficulty::Normal;
+        }
+        if let Ok(difficulty) = response.parse() {
+            break difficulty;
+        }
+        println!("{} is not a valid difficulty.", response);
+    }
+}
+
 /// Helper function for prompting the player with a yes/no question
 pub fn ask(message: &str) -> bool {
     let mut message = message.to_owned();
@@ -1,12 +1,22 @@
 //! contains types that represent units that appear in the game
 
-use crate::Direction;
+use std::cmp;
 
-/// The Warrior (our protagonist), enemy Sludges and Archers, and Captives.
+use crate::{
+    Direction,
+    effect::{Effect, StatusEffect},
+    engine::ranged::Ranged,
+    equipment::Equipment,
+    item::Item,
+};
+
+/// The Warrior (our protagonist), their Golem companion, enemy Sludges and
+/// Archers, and Captives.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum UnitType {
     Archer,
     Captive,
+    Golem,
     Sludge,
     ThickSludge,
     Warrior,
@@ -19,6 +29,7 @@ impl UnitType {
         match self {
             UnitType::Archer => "a",
             UnitType::Captive => "C",
+            UnitType::Golem => "G",
             UnitType::Sludge => "s",
             UnitType::ThickSludge => "S",
             UnitType::Warrior => "@",
@@ -27,13 +38,21 @@ impl UnitType {
     }
 }
 
-/// The state of a unit: its `position`, current/max `hp`, and `atk` power.
+/// The state of a unit: its `position`, current/max `hp`, `atk` power,
+/// `ranged` attack (if it has one), the status effect its attacks
+/// `inflicts` (if any), the status `effects` it currently suffers from, and
+/// `equipment` and `inventory` (only the warrior has any).
 #[derive(Clone, Debug)]
 pub struct Unit {
     pub unit_type: UnitType,
     pub position: (i32, i32),
     pub hp: (i32, i32),
     pub atk: i32,
+    pub ranged: Option<Ranged>,
+    pub inflicts: Option<StatusEffect>,
+    pub effects: Vec<StatusEffect>,
+    pub equipment: Option<Equipment>,
+    pub inventory: Vec<Item>,
     pub facing: Option<Direction>,
 }
 
@@ -43,6 +62,7 @@ impl Unit {
         match unit_type {
             UnitType::Archer => Unit::archer(position),
             UnitType::Captive => Unit::captive(position),
+            UnitType::Golem => Unit::golem(position),
             UnitType::Sludge => Unit::sludge(position),
             UnitType::ThickSludge => Unit::thick_sludge(position),
             UnitType::Warrior => Unit::warrior(position),
@@ -50,13 +70,18 @@ impl Unit {
         }
     }
 
-    /// Create a unit of type Archer (7 HP, 3 ATK) at `position`.
+    /// Create a unit of type Archer (7 HP, 3 ATK, 3 range) at `position`.
     pub fn archer(position: (i32, i32)) -> Unit {
         Unit {
             unit_type: UnitType::Archer,
             position,
             hp: (7, 7),
             atk: 3,
+            ranged: Some(Ranged::new(3, 3)),
+            inflicts: None,
+            effects: Vec::new(),
+            equipment: None,
+            inventory: Vec::new(),
             facing: None,
         }
     }
@@ -68,17 +93,44 @@ impl Unit {
             position,
             hp: (1, 1),
             atk: 0,
+            ranged: None,
+            inflicts: None,
+            effects: Vec::new(),
+            equipment: None,
+            inventory: Vec::new(),
             facing: None,
         }
     }
 
+    /// Create a unit of type Golem (10 HP, 3 ATK) at `position`.
+    pub fn golem(position: (i32, i32)) -> Unit {
+        Unit {
+            unit_type: UnitType::Golem,
+            position,
+            hp: (10, 10),
+            atk: 3,
+            ranged: None,
+            inflicts: None,
+            effects: Vec::new(),
+            equipment: None,
+            inventory: Vec::new(),
+            facing: Some(Direction::Forward),
+        }
+    }
+
     /// Create a unit of type Sludge (12 HP, 3 ATK) at `position`.
+    /// Its attacks poison for 1 turn.
     pub fn sludge(position: (i32, i32)) -> Unit {
         Unit {
             unit_type: UnitType::Sludge,
             position,
             hp: (12, 12),
             atk: 3,
+            ranged: None,
+            inflicts: Some(StatusEffect::new(Effect::Poisoned, 1)),
+            effects: Vec::new(),
+            equipment: None,
+            inventory: Vec::new(),
             facing: None,
         }
     }
@@ -90,29 +142,94 @@ impl Unit {
             position,
             hp: (18, 18),
             atk: 3,
+            ranged: None,
+            inflicts: None,
+            effects: Vec::new(),
+            equipment: None,
+            inventory: Vec::new(),
             facing: None,
         }
     }
 
     /// Create a unit of type Warrior (20 HP, 5 ATK) at `position`.
+    /// The warrior starts out with the default
+    /// [`Equipment`](crate::equipment::Equipment).
     pub fn warrior(position: (i32, i32)) -> Unit {
-        Unit {
+        let mut warrior = Unit {
             unit_type: UnitType::Warrior,
             position,
             hp: (20, 20),
             atk: 5,
+            ranged: None,
+            inflicts: None,
+            effects: Vec::new(),
+            equipment: None,
+            inventory: Vec::new(),
             facing: Some(Direction::Forward),
-        }
+        };
+        warrior.equip(Equipment::default());
+        warrior
     }
 
-    /// Create a unit of type Wizard (3 HP, 11 ATK) at `position`.
+    /// Create a unit of type Wizard (3 HP, 11 ATK, 3 range) at `position`.
+    /// Its bolts stun for 1 turn, and it holds its next bolt until the stun
+    /// has worn off.
     pub fn wizard(position: (i32, i32)) -> Unit {
         Unit {
             unit_type: UnitType::Wizard,
             position,
             hp: (3, 3),
             atk: 11,
+            ranged: Some(Ranged::new(11, 3)),
+            inflicts: Some(StatusEffect::new(Effect::Stunned, 1)),
+            effects: Vec::new(),
+            equipment: None,
+            inventory: Vec::new(),
             facing: None,
         }
     }
+
+    /// Give the unit an `equipment` loadout. Its weapon determines `atk`
+    /// and its bow determines the `ranged` attack.
+    pub fn equip(&mut self, equipment: Equipment) {
+        self.atk = equipment.weapon.atk;
+        self.ranged = equipment.bow.map(|bow| bow.ranged);
+        self.equipment = Some(equipment);
+    }
+
+    /// Reduce the unit's HP by `atk`, minus whatever its armor absorbs.
+    /// Returns the damage that was taken.
+    pub fn take_damage(&mut self, atk: i32) -> i32 {
+        let defense = match self.equipment {
+            Some(equipment) => equipment.defense(),
+            None => 0,
+        };
+        let (current, max) = self.hp;
+        let damage = cmp::max(atk - defense, 0);
+        self.hp = (cmp::max(current - damage, 0), max);
+        damage
+    }
+
+    /// Reduce the unit's HP by `damage`, which no armor can absorb (e.g.
+    /// poison). Returns the damage that was taken.
+    pub fn lose_hp(&mut self, damage: i32) -> i32 {
+        let (current, max) = self.hp;
+        let damage = cmp::min(damage, current);
+        self.hp = (current - damage, max);
+        damage
+    }
+
+    /// Afflict the unit with `status`. If the unit already suffers from the
+    /// same effect, it lasts for whichever duration is longer.
+    pub fn afflict(&mut self, status: StatusEffect) {
+        match self.effects.iter_mut().find(|e| e.effect == status.effect) {
+            Some(existing) => existing.turns = cmp::max(existing.turns, status.turns),
+            None => self.effects.push(status),
+        }
+    }
+
+    /// Returns `true` if the unit currently suffers from `effect`.
+    pub fn has_effect(&self, effect: Effect) -> bool {
+        self.effects.iter().any(|e| e.effect == effect)
+    }
 }
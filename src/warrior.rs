@@ -2,7 +2,10 @@
 
 use crate::{
     actions::{Action, Direction},
+    effect::{Effect, StatusEffect},
+    equipment::Equipment,
     floor::Tile,
+    item::Item,
 };
 use std::cell::RefCell;
 
@@ -20,6 +23,10 @@ use std::cell::RefCell;
 /// Available abilities:
 ///
 /// * [`walk`](crate::warrior::Warrior::walk)
+/// * [`equipment`](crate::warrior::Warrior::equipment)
+/// * [`inventory`](crate::warrior::Warrior::inventory)
+/// * [`use_item`](crate::warrior::Warrior::use_item)
+/// * [`effects`](crate::warrior::Warrior::effects) and [`is`](crate::warrior::Warrior::is)
 ///
 /// **Level 2**
 ///
@@ -37,7 +44,8 @@ use std::cell::RefCell;
 ///
 /// **Level 4**
 ///
-/// *No new abilities unlocked at this level!*
+/// *No new abilities unlocked at this level!* However, the Warrior finds
+/// some armor (see [`Armor::leather`](crate::equipment::Armor::leather)).
 ///
 /// **Level 5**
 ///
@@ -54,6 +62,9 @@ use std::cell::RefCell;
 /// * `attack` -> [`attack_toward`](crate::warrior::Warrior::attack_toward)
 /// * `rescue` -> [`rescue_toward`](crate::warrior::Warrior::rescue_toward)
 ///
+/// The Warrior also finds a better weapon at this level (see
+/// [`Weapon::long_sword`](crate::equipment::Weapon::long_sword)).
+///
 /// **Level 7**
 ///
 /// New abilities unlocked at this level:
@@ -67,15 +78,29 @@ use std::cell::RefCell;
 /// * [`look`](crate::warrior::Warrior::look) -> [`look_toward`](crate::warrior::Warrior::look_toward)
 /// * [`shoot`](crate::warrior::Warrior::shoot) -> [`shoot_toward`](crate::warrior::Warrior::shoot_toward)
 ///
+/// The Warrior also finds a bow at this level (see
+/// [`Bow::short_bow`](crate::equipment::Bow::short_bow)).
+///
 /// **Level 9**
 ///
-/// *No new abilities unlocked at this level!*
+/// *No new abilities unlocked at this level!* However, the Warrior can now
+/// *feel* hazards: [`check`](crate::warrior::Warrior::check) and
+/// [`look`](crate::warrior::Warrior::look) report a
+/// [`Tile::Hazard`](crate::Tile::Hazard) where they used to report
+/// `Tile::Empty`. The Warrior also trades up to heavier armor and a bow that
+/// shoots (and sees) further, but with a limited number of arrows (see
+/// [`Armor::chain_mail`](crate::equipment::Armor::chain_mail) and
+/// [`Bow::long_bow`](crate::equipment::Bow::long_bow)).
 pub struct Warrior {
     level: usize,
     ahead: Vec<Tile>,
     behind: Vec<Tile>,
     health: i32,
     facing: Direction,
+    equipment: Equipment,
+    inventory: Vec<Item>,
+    effects: Vec<StatusEffect>,
+    golem: bool,
     action: RefCell<Option<Action>>,
     warnings: RefCell<Vec<String>>,
 }
@@ -94,11 +119,57 @@ impl Warrior {
             behind,
             health,
             facing,
+            equipment: Equipment::for_level(level),
+            inventory: Vec::new(),
+            effects: Vec::new(),
+            golem: false,
             action: RefCell::new(None),
             warnings: RefCell::new(Vec::new()),
         }
     }
 
+    /// The restricted view given to the [`Player`](crate::player::Player)
+    /// controlling a Golem companion (see
+    /// [`Game::play_with_golem`](crate::game::Game::play_with_golem)). A golem
+    /// has the abilities of a **Level 7** Warrior, except that it cannot
+    /// `rest`, `rescue`, or `use_item`.
+    pub fn golem(ahead: Vec<Tile>, behind: Vec<Tile>, health: i32, facing: Direction) -> Warrior {
+        let mut golem = Warrior::new(7, ahead, behind, health, facing);
+        golem.golem = true;
+        golem
+    }
+
+    /// Returns `true` if this is the view given to a Golem companion rather
+    /// than to the Warrior.
+    pub fn is_golem(&self) -> bool {
+        self.golem
+    }
+
+    // the level whose abilities the Warrior has learned
+    pub(crate) fn level(&self) -> usize {
+        self.level
+    }
+
+    /// Replace the loadout earned at the Warrior's level (see
+    /// [`Equipment::for_level`](crate::equipment::Equipment::for_level))
+    /// with `equipment`.
+    pub fn with_equipment(mut self, equipment: Equipment) -> Warrior {
+        self.equipment = equipment;
+        self
+    }
+
+    /// Give the Warrior the items in `inventory`.
+    pub fn with_inventory(mut self, inventory: Vec<Item>) -> Warrior {
+        self.inventory = inventory;
+        self
+    }
+
+    /// Afflict the Warrior with the status `effects`.
+    pub fn with_effects(mut self, effects: Vec<StatusEffect>) -> Warrior {
+        self.effects = effects;
+        self
+    }
+
     /// Walk forward one tile.
     /// This is an [`Action`](crate::actions::Action).
     /// This ability is available at **Level 1**.
@@ -155,8 +226,9 @@ impl Warrior {
         }
     }
 
-    /// Check three tiles in front of the Warrior.
-    /// Returns a vector of up to three [`Tile`](crate::Tile)s.
+    /// Check the tiles in front of the Warrior, as far as their bow can
+    /// shoot (three tiles for the Short Bow, five for the Long Bow).
+    /// Returns a vector of up to that many [`Tile`](crate::Tile)s.
     /// This ability is unlocked at **Level 8**.
     pub fn look(&self) -> &Vec<Tile> {
         if self.level < 8 {
@@ -165,8 +237,9 @@ impl Warrior {
         self.look_toward(Direction::Forward)
     }
 
-    /// Check three tiles toward specified `direction`.
-    /// Returns a vector of up to three [`Tile`](crate::Tile)s.
+    /// Check the tiles toward specified `direction`, as far as the Warrior's
+    /// bow can shoot. Returns a vector of up to that many
+    /// [`Tile`](crate::Tile)s.
     /// This ability is unlocked at **Level 8**.
     pub fn look_toward(&self, direction: Direction) -> &Vec<Tile> {
         if self.level < 8 {
@@ -212,13 +285,31 @@ impl Warrior {
         self.health
     }
 
-    /// Rest and regain 10% of the Warrior's HP.
+    /// Check the [`StatusEffect`](crate::effect::StatusEffect)s the Warrior
+    /// currently suffers from, and how many turns each will last.
+    /// This ability is available at **Level 1**.
+    pub fn effects(&self) -> &Vec<StatusEffect> {
+        &self.effects
+    }
+
+    /// Returns `true` if the Warrior currently suffers from `effect`.
+    /// This ability is available at **Level 1**.
+    pub fn is(&self, effect: Effect) -> bool {
+        self.effects.iter().any(|status| status.effect == effect)
+    }
+
+    /// Rest and regain 10% of the Warrior's HP (more on Easy
+    /// [`Difficulty`](crate::difficulty::Difficulty), less on Hard).
+    /// Resting is not possible while [`Poisoned`](crate::effect::Effect::Poisoned).
     /// This is an [`Action`](crate::actions::Action).
     /// This ability is unlocked at **Level 3**.
     pub fn rest(&self) {
         if self.level < 3 {
             panic!("You have not yet learned `rest`!");
         }
+        if self.golem {
+            panic!("A golem cannot `rest`!");
+        }
         self.perform(Action::Rest);
     }
 
@@ -229,6 +320,9 @@ impl Warrior {
         if self.level < 5 {
             panic!("You have not yet learned `rescue`!");
         }
+        if self.golem {
+            panic!("A golem cannot `rescue`!");
+        }
         self.perform_rescue(Direction::Forward);
     }
 
@@ -239,6 +333,9 @@ impl Warrior {
         if self.level < 6 {
             panic!("You have not yet learned `rescue_toward`! Perhaps you meant `rescue`?")
         }
+        if self.golem {
+            panic!("A golem cannot `rescue_toward`!");
+        }
         self.perform_rescue(direction);
     }
 
@@ -261,7 +358,33 @@ impl Warrior {
         self.perform(Action::Pivot(direction));
     }
 
-    /// Fire an arrow up to three tiles in front of the Warrior.
+    /// Check the Warrior's current [`Equipment`](crate::equipment::Equipment):
+    /// their weapon, their bow (and how many arrows are left), and their armor.
+    /// This ability is available at **Level 1**.
+    pub fn equipment(&self) -> &Equipment {
+        &self.equipment
+    }
+
+    /// Check the [`Item`](crate::item::Item)s the Warrior has picked up by
+    /// walking onto them.
+    /// This ability is available at **Level 1**.
+    pub fn inventory(&self) -> &Vec<Item> {
+        &self.inventory
+    }
+
+    /// Use an [`Item`](crate::item::Item) from the Warrior's inventory, for
+    /// example drinking a `HealthPotion` instead of resting.
+    /// This is an [`Action`](crate::actions::Action).
+    /// This ability is available at **Level 1**.
+    pub fn use_item(&self, item: Item) {
+        if self.golem {
+            panic!("A golem cannot `use_item`!");
+        }
+        self.perform(Action::Use(item));
+    }
+
+    /// Fire an arrow in front of the Warrior, as far as the bow's range
+    /// (three tiles for the Short Bow). Each shot uses up one arrow.
     /// This is an [`Action`](crate::actions::Action).
     /// This ability is unlocked at **Level 8**.
     pub fn shoot(&self) {
@@ -271,7 +394,7 @@ impl Warrior {
         self.shoot_toward(Direction::Forward);
     }
 
-    /// Fire an arrow up to three tiles toward specified `direction`.
+    /// Fire an arrow toward specified `direction`, as far as the bow's range.
     /// This is an [`Action`](crate::actions::Action).
     /// This ability is unlocked at **Level 8**.
     pub fn shoot_toward(&self, direction: Direction) {
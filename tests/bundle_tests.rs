@@ -0,0 +1,88 @@
+mod common;
+
+use std::fs;
+
+use rust_warrior::{
+    bundle::{Bundle, BundleFile},
+    profile::Profile,
+    replay::{REPLAY_PATH, Replay},
+    starter,
+};
+
+#[test]
+fn test_export_and_import() {
+    let dir = common::temp_dir("bundle-tests");
+    let player_dir =
+        starter::generate_in(Profile::new(String::from("Bot")), &dir.join("from")).unwrap();
+    fs::create_dir(player_dir.join("src").join("bin")).unwrap();
+    fs::write(
+        player_dir.join("src").join("bin").join("extra.rs"),
+        "fn main() {}\n",
+    )
+    .unwrap();
+    let replay = Replay {
+        player: String::from("Bot"),
+        level: 1,
+        score: 48,
+        frames: vec![vec![String::from(" --\n|@>|\n --")]],
+    };
+    replay.save(&player_dir.join(REPLAY_PATH)).unwrap();
+
+    let bundle = Bundle::export(&player_dir).unwrap();
+    assert_eq!(bundle.file_name(), "bot-level-1.bundle.toml");
+    assert_eq!(bundle.score, Some(48));
+    let paths: Vec<&str> = bundle.files.iter().map(|file| file.path.as_str()).collect();
+    assert_eq!(paths, vec!["Cargo.toml", "src/bin/extra.rs", "src/main.rs"]);
+
+    let path = dir.join(bundle.file_name());
+    bundle.save(&path).unwrap();
+    let bundle = Bundle::load(&path).unwrap();
+    let imported = bundle.import(&dir.join("to")).unwrap();
+    assert_eq!(imported, dir.join("to").join("bot"));
+    for file in [
+        "src/main.rs",
+        "src/bin/extra.rs",
+        "Cargo.toml",
+        ".profile",
+        "README.md",
+        "tests/levels.rs",
+    ] {
+        assert_eq!(
+            fs::read(imported.join(file)).unwrap(),
+            fs::read(player_dir.join(file)).unwrap(),
+            "{} differs",
+            file
+        );
+    }
+    assert_eq!(Replay::load(&imported.join(REPLAY_PATH)).unwrap(), replay);
+
+    let err = bundle.import(&dir.join("to")).unwrap_err();
+    assert!(err.ends_with("already exists"));
+
+    let mut sneaky = bundle.clone();
+    sneaky.files.push(BundleFile {
+        path: String::from("../../escaped.rs"),
+        contents: String::new(),
+    });
+    let err = sneaky.import(&dir.join("elsewhere")).unwrap_err();
+    assert_eq!(
+        err,
+        "the bundle contains an invalid path `../../escaped.rs`"
+    );
+
+    // whatever the warrior is named, they are imported right into the
+    // rustwarrior directory
+    for (name, directory) in [
+        ("../../escaped", "escaped"),
+        ("/tmp/escaped", "tmp-escaped"),
+    ] {
+        let mut hostile = bundle.clone();
+        hostile.profile = Profile::new(String::from(name)).to_toml();
+        let imported = hostile.import(&dir.join("hostile")).unwrap();
+        assert_eq!(imported, dir.join("hostile").join(directory));
+        assert!(imported.join(".profile").exists());
+    }
+    assert!(!dir.join("escaped").exists());
+
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,74 @@
+mod common;
+
+use std::fs;
+
+use rust_warrior::{cli, replay::Replay};
+
+#[test]
+fn test_cli_errors() {
+    let err = cli::run(&args(&["bogus"])).unwrap_err();
+    assert!(err.starts_with("unknown command `bogus`"));
+
+    let err = cli::run(&args(&["new"])).unwrap_err();
+    assert_eq!(err, "`new` requires --name <name>");
+
+    let err = cli::run(&args(&["new", "--name", "Bot", "--tower", "ivory"])).unwrap_err();
+    assert_eq!(err, "unknown tower `ivory` (available towers: beginner)");
+
+    let err = cli::run(&args(&["show", "10"])).unwrap_err();
+    assert_eq!(err, "`10` is not a level (choose from 1 to 9)");
+
+    let err = cli::run(&args(&["practice", "--seed", "lucky"])).unwrap_err();
+    assert_eq!(err, "`lucky` is not a valid seed");
+
+    let err = cli::run(&args(&["levels", "--verbose"])).unwrap_err();
+    assert!(err.starts_with("unexpected option `--verbose`"));
+}
+
+#[test]
+fn test_cli_new() {
+    let dir = common::temp_dir("cli-tests");
+    let dir_arg = dir.to_str().unwrap();
+    cli::run(&args(&["new", "--name", "Bot", "--dir", dir_arg])).unwrap();
+
+    let player_dir = dir.join("bot");
+    for file in [
+        "src/main.rs",
+        "tests/levels.rs",
+        "Cargo.toml",
+        ".profile",
+        "README.md",
+    ] {
+        assert!(player_dir.join(file).exists(), "{} was not generated", file);
+    }
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_replay_save_and_load() {
+    let path = common::temp_dir("replay-tests").join("replay.toml");
+    let replay = Replay {
+        player: String::from("Bot"),
+        level: 2,
+        score: 36,
+        frames: vec![
+            vec![String::from(" --\n|@>|\n --")],
+            vec![
+                String::from(" --\n| @|\n --"),
+                String::from("Bot walks Forward"),
+            ],
+        ],
+    };
+    replay.save(&path).unwrap();
+    assert_eq!(Replay::load(&path).unwrap(), replay);
+
+    fs::remove_file(&path).unwrap();
+    assert!(Replay::load(&path).is_err());
+}
+
+// Test Helpers
+
+fn args(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| String::from(*arg)).collect()
+}
@@ -0,0 +1,39 @@
+//! helpers shared by the integration tests (not every test uses all of them)
+#![allow(dead_code)]
+
+use std::{env, fs, path::PathBuf, process, sync::Once};
+
+use rust_warrior::{Player, Warrior};
+
+/// A `Player` that walks forward, whatever is in the way.
+pub struct Walker;
+
+impl Player for Walker {
+    fn play_turn(&mut self, warrior: &Warrior) {
+        warrior.walk();
+    }
+}
+
+/// Plays levels drawn to the screen (see `engine::start`) without a delay
+/// between turns. Every test in a file which calls this must call it before
+/// playing, so that the environment is not read while it is being set.
+pub fn without_delay() {
+    static SET_DELAY: Once = Once::new();
+    SET_DELAY.call_once(|| {
+        // SAFETY: the variable is set once, before any test of the file reads
+        // the environment
+        unsafe { env::set_var("GAME_LOOP_DELAY", "0") };
+    });
+}
+
+/// An empty directory under the system's temp directory, unique to the test
+/// `name` and this test run, so that tests running at the same time never
+/// share files.
+pub fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("rust-warrior-{}-{}", name, process::id()));
+    if dir.exists() {
+        fs::remove_dir_all(&dir).unwrap();
+    }
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
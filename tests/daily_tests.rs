@@ -0,0 +1,30 @@
+use rust_warrior::{
+    daily,
+    floor::Floor,
+    generator::{self, Generator},
+};
+
+#[test]
+fn test_seed() {
+    assert_eq!(daily::seed("2024-03-01"), daily::seed("2024-03-01"));
+    assert_ne!(daily::seed("2024-03-01"), daily::seed("2024-03-02"));
+}
+
+#[test]
+fn test_floor() {
+    let floor = daily::floor("2024-03-01");
+    assert_eq!(floor.draw(), daily::floor("2024-03-01").draw());
+    assert_eq!(floor.width, daily::WIDTH);
+    assert!(generator::is_solvable(&floor, Floor::count()));
+
+    let generator =
+        Generator::new(daily::seed("2024-03-01"), Floor::count()).with_width(daily::WIDTH);
+    assert_eq!(floor.draw(), generator.generate().draw());
+}
+
+#[test]
+fn test_today() {
+    let today = daily::today();
+    assert_eq!(today.len(), 10);
+    assert_eq!(today.matches('-').count(), 2);
+}
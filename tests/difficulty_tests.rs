@@ -0,0 +1,69 @@
+mod common;
+
+use common::Walker;
+use rust_warrior::{
+    difficulty::Difficulty, engine::outcome::Outcome, floor::Floor, testing, unit::Unit,
+};
+
+#[test]
+fn test_scale() {
+    let mut sludge = Unit::sludge((0, 0));
+    Difficulty::Easy.scale(&mut sludge);
+    assert_eq!(sludge.hp, (9, 9));
+    assert_eq!(sludge.atk, 2);
+
+    let mut wizard = Unit::wizard((0, 0));
+    Difficulty::Hard.scale(&mut wizard);
+    assert_eq!(wizard.hp, (3, 3));
+    assert_eq!(wizard.atk, 13);
+    assert_eq!(wizard.ranged.unwrap().atk, 13);
+
+    // only enemies are scaled
+    let mut warrior = Unit::warrior((0, 0));
+    Difficulty::Hard.scale(&mut warrior);
+    assert_eq!(warrior.hp, (20, 20));
+
+    let floor = Floor::load(2).with_difficulty(Difficulty::Hard);
+    assert_eq!(floor.difficulty, Difficulty::Hard);
+    assert_eq!(floor.units[1].hp, (14, 14));
+}
+
+#[test]
+fn test_from_str() {
+    assert_eq!("hard".parse(), Ok(Difficulty::Hard));
+    assert_eq!("Easy".parse(), Ok(Difficulty::Easy));
+    assert_eq!(
+        "brutal".parse::<Difficulty>(),
+        Err(String::from(
+            "unknown difficulty `brutal` (choose from easy, normal, or hard)"
+        ))
+    );
+}
+
+#[test]
+fn test_score() {
+    let outcome = Outcome {
+        turns: 30,
+        enemies_defeated: 1,
+        ..Outcome::default()
+    };
+    assert_eq!(outcome.score(), 36);
+    let hard = Outcome {
+        difficulty: Difficulty::Hard,
+        ..outcome.clone()
+    };
+    assert_eq!(hard.score(), 54);
+    let easy = Outcome {
+        difficulty: Difficulty::Easy,
+        ..outcome
+    };
+    assert_eq!(easy.score(), 18);
+}
+
+#[test]
+fn test_turn_limit() {
+    // the stairs cannot be reached by walking into the wall
+    let floor = Floor::parse("> @").with_difficulty(Difficulty::Hard);
+    let outcome = testing::run_level(|| Box::new(Walker), floor);
+    assert_eq!(outcome.turns, Difficulty::Hard.turn_limit());
+}
@@ -0,0 +1,117 @@
+mod common;
+
+use common::Walker;
+use rust_warrior::{
+    Direction, Player, Tile, Warrior, difficulty::Difficulty, engine, floor::Floor,
+    player::PlayerGenerator,
+};
+
+#[test]
+fn test_start_with_golem() {
+    common::without_delay();
+    // |a @     >|: the golem is summoned behind the warrior, and fends off
+    // the archer while they make for the stairs
+    let floor = Floor::parse("a @     >");
+    let outcome = engine::start_with_golem(
+        String::from("Warrior"),
+        1,
+        floor,
+        || Box::new(Walker),
+        Some(|| Box::new(Guard)),
+    );
+    assert!(outcome.is_success());
+    assert_eq!(outcome.enemies_defeated, 1);
+}
+
+#[test]
+fn test_coop() {
+    common::without_delay();
+    let floor = Floor::parse(" @   >").with_party(2);
+    let players = vec![
+        (String::from("Alice"), walker as PlayerGenerator),
+        (String::from("Bob"), walker as PlayerGenerator),
+    ];
+    let outcome = engine::start_coop(1, floor, players).unwrap();
+    assert!(outcome.is_success());
+
+    // the level is not over until every warrior reaches the stairs
+    let floor = Floor::parse(" @   >").with_party(2);
+    let players = vec![
+        (String::from("Alice"), walker as PlayerGenerator),
+        (String::from("Bob"), idle as PlayerGenerator),
+    ];
+    let outcome = engine::start_coop(1, floor, players).unwrap();
+    assert_eq!(
+        outcome.failure.as_deref(),
+        Some("Bob seems to have gotten lost...")
+    );
+    assert_eq!(outcome.turns, Difficulty::Normal.turn_limit());
+}
+
+#[test]
+fn test_versus() {
+    common::without_delay();
+    let players = [
+        (String::from("Alice"), idle as PlayerGenerator),
+        (String::from("Bob"), fighter as PlayerGenerator),
+    ];
+    let summary = engine::start_versus(Floor::count(), Floor::arena(), players).unwrap();
+    assert_eq!(summary.winner.as_deref(), Some("Bob"));
+    assert_eq!(summary.warriors[0].1.0, 0);
+    assert!(summary.turns < Difficulty::Normal.turn_limit());
+}
+
+#[test]
+fn test_versus_draw() {
+    common::without_delay();
+    let players = [
+        (String::from("Alice"), idle as PlayerGenerator),
+        (String::from("Bob"), idle as PlayerGenerator),
+    ];
+    let summary = engine::start_versus(Floor::count(), Floor::arena(), players).unwrap();
+    assert_eq!(summary.winner, None);
+    assert_eq!(summary.turns, Difficulty::Normal.turn_limit());
+}
+
+// Test Helpers
+
+struct Idle;
+
+impl Player for Idle {
+    fn play_turn(&mut self, _warrior: &Warrior) {}
+}
+
+// walks up to whatever is ahead, and attacks it
+struct Fighter;
+
+impl Player for Fighter {
+    fn play_turn(&mut self, warrior: &Warrior) {
+        match warrior.check() {
+            Tile::Unit(_) => warrior.attack(),
+            _ => warrior.walk(),
+        }
+    }
+}
+
+fn walker() -> Box<dyn Player + Send + Sync> {
+    Box::new(Walker)
+}
+
+fn idle() -> Box<dyn Player + Send + Sync> {
+    Box::new(Idle)
+}
+
+fn fighter() -> Box<dyn Player + Send + Sync> {
+    Box::new(Fighter)
+}
+
+// a golem which attacks whatever comes up behind it
+struct Guard;
+
+impl Player for Guard {
+    fn play_turn(&mut self, warrior: &Warrior) {
+        if let Tile::Unit(_) = warrior.check_toward(Direction::Backward) {
+            warrior.attack_toward(Direction::Backward);
+        }
+    }
+}
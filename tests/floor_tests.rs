@@ -1,4 +1,13 @@
-use rust_warrior::{floor::Floor, unit::Unit, Tile, UnitType};
+use rust_warrior::{
+    Hazard, Item, Player, Tile, UnitType, Warrior, engine, floor::Floor, player::PlayerGenerator,
+    unit::Unit,
+};
+
+struct Idle;
+
+impl Player for Idle {
+    fn play_turn(&mut self, _warrior: &Warrior) {}
+}
 
 #[test]
 fn test_tile_draw() {
@@ -10,6 +19,9 @@ fn test_tile_draw() {
 
     let t = Tile::Unit(UnitType::Warrior);
     assert_eq!(t.draw(), "@");
+
+    let t = Tile::Item(Item::HealthPotion);
+    assert_eq!(t.draw(), "!");
 }
 
 #[test]
@@ -19,6 +31,7 @@ fn test_floor_tile() {
         height: 1,
         stairs: (2, 0),
         units: vec![Unit::warrior((0, 0))],
+        ..Floor::default()
     };
 
     let t = f.tile((0, 0));
@@ -46,3 +59,123 @@ fn test_floor_load() {
     let t = f.tile((7, 0));
     assert_eq!(t, Tile::Stairs);
 }
+
+#[test]
+fn test_floor_items() {
+    let mut f = Floor {
+        width: 4,
+        height: 1,
+        stairs: (3, 0),
+        units: vec![Unit::warrior((0, 0))],
+        items: vec![(Item::Key, (1, 0)), (Item::ArrowBundle, (2, 0))],
+        ..Floor::default()
+    };
+
+    assert_eq!(f.tile((1, 0)), Tile::Item(Item::Key));
+    assert_eq!(f.draw(), " ----\n|@k}>|\n ----");
+
+    assert_eq!(f.take_item((1, 0)), Some(Item::Key));
+    assert_eq!(f.take_item((1, 0)), None);
+    assert_eq!(f.tile((1, 0)), Tile::Empty);
+    assert_eq!(f.item((2, 0)), Some(Item::ArrowBundle));
+}
+
+#[test]
+fn test_floor_doors() {
+    let mut f = Floor {
+        width: 4,
+        height: 1,
+        stairs: (3, 0),
+        units: vec![Unit::warrior((0, 0))],
+        doors: vec![(2, 0)],
+        sealed_stairs: true,
+        ..Floor::default()
+    };
+
+    assert_eq!(f.tile((2, 0)), Tile::Door);
+    assert_eq!(f.draw(), " ----\n|@ #>|\n ----");
+    assert!(!f.stairs_open());
+
+    assert!(f.open_door((2, 0)));
+    assert!(!f.open_door((2, 0)));
+    assert_eq!(f.tile((2, 0)), Tile::Empty);
+    assert!(f.stairs_open());
+}
+
+#[test]
+fn test_floor_hazards() {
+    let f = Floor {
+        width: 5,
+        height: 1,
+        stairs: (4, 0),
+        units: vec![Unit::warrior((0, 0))],
+        hazards: vec![
+            (Hazard::Spikes, (1, 0)),
+            (Hazard::PoisonPool, (2, 0)),
+            (Hazard::PressurePlate, (3, 0)),
+        ],
+        ..Floor::default()
+    };
+
+    assert_eq!(f.tile((1, 0)), Tile::Hazard(Hazard::Spikes));
+    assert_eq!(f.hazard((3, 0)), Some(Hazard::PressurePlate));
+    assert_eq!(f.hazard((4, 0)), None);
+    assert_eq!(f.draw(), " -----\n|@^~_>|\n -----");
+}
+
+#[test]
+fn test_floor_arena() {
+    let f = Floor::arena();
+    assert!(f.is_symmetric());
+    assert_eq!(f.draw(), " ----------\n|@ ! ^^ ! @|\n ----------");
+
+    assert!(!Floor::load(1).is_symmetric());
+}
+
+#[test]
+fn test_floor_with_party() {
+    let f = Floor::load(2).with_party(3);
+    assert_eq!(f.draw(), " --------\n|@@@ s  >|\n --------");
+
+    let f = Floor::load(6).with_party(2);
+    assert_eq!(f.tile((1, 0)), Tile::Unit(UnitType::Warrior));
+}
+
+#[test]
+fn test_floor_rejects_mismatched_players() {
+    let idle: PlayerGenerator = || Box::new(Idle);
+    let players = vec![(String::from("A"), idle), (String::from("B"), idle)];
+    let result = engine::start_coop(1, Floor::load(1), players);
+    assert_eq!(
+        result.err(),
+        Some(String::from("the floor has 1 warrior(s) for 2 player(s)"))
+    );
+
+    let players = [(String::from("A"), idle), (String::from("B"), idle)];
+    let result = engine::start_versus(1, Floor::load(1).with_party(2), players);
+    assert_eq!(
+        result.err(),
+        Some(String::from(
+            "a versus match must be played on a symmetric floor"
+        ))
+    );
+}
+
+#[test]
+fn test_floor_parse() {
+    let f = Floor::parse("@!^#C>");
+    assert_eq!((f.width, f.height), (6, 1));
+    assert_eq!(f.stairs, (5, 0));
+    assert_eq!(f.tile((0, 0)), Tile::Unit(UnitType::Warrior));
+    assert_eq!(f.tile((1, 0)), Tile::Item(Item::HealthPotion));
+    assert_eq!(f.tile((2, 0)), Tile::Hazard(Hazard::Spikes));
+    assert_eq!(f.tile((3, 0)), Tile::Door);
+    assert_eq!(f.tile((4, 0)), Tile::Unit(UnitType::Captive));
+    assert_eq!(f.draw(), " ------\n|@!^#C>|\n ------");
+}
+
+#[test]
+#[should_panic(expected = "unrecognized tile 'x' in floor")]
+fn test_floor_parse_unrecognized() {
+    Floor::parse("@x>");
+}
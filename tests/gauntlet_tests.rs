@@ -0,0 +1,97 @@
+mod common;
+
+use common::Walker;
+use rust_warrior::{
+    Item, UnitType,
+    difficulty::Difficulty,
+    engine::outcome::Outcome,
+    equipment::{Bow, Equipment},
+    floor::Floor,
+    gauntlet::Gauntlet,
+    objective::Objective,
+    testing,
+};
+
+#[test]
+fn test_next_floor_carries_over() {
+    let mut gauntlet = Gauntlet::new(Difficulty::Normal);
+    assert_eq!(gauntlet.level(), 1);
+    gauntlet.record(Outcome {
+        hp: (12, 20),
+        inventory: vec![Item::HealthPotion],
+        ..Outcome::default()
+    });
+    assert_eq!(gauntlet.level(), 2);
+    let floor = gauntlet.next_floor().unwrap();
+    let warrior = floor
+        .units
+        .iter()
+        .find(|unit| unit.unit_type == UnitType::Warrior)
+        .unwrap();
+    assert_eq!(warrior.hp, (12, 20));
+    assert_eq!(warrior.inventory, vec![Item::HealthPotion]);
+}
+
+#[test]
+fn test_next_floor_carries_over_ammo() {
+    let mut gauntlet = Gauntlet::new(Difficulty::Normal);
+    let mut equipment = Equipment::for_level(9);
+    equipment.bow = Some(Bow::long_bow(3));
+    gauntlet.record(Outcome {
+        hp: (20, 20),
+        equipment: Some(equipment),
+        ..Outcome::default()
+    });
+
+    // the quiver is not refilled on the next floor
+    let floor = gauntlet.next_floor().unwrap();
+    let outcome = testing::run_level_at(|| Box::new(Walker), gauntlet.top, floor);
+    let ammo = outcome
+        .equipment
+        .and_then(|equipment| equipment.bow)
+        .and_then(|bow| bow.ammo);
+    assert_eq!(ammo, Some(3));
+}
+
+#[test]
+fn test_gauntlet_ends() {
+    let mut gauntlet = Gauntlet::to_level(Difficulty::Normal, 2);
+    gauntlet.record(Outcome::default());
+    assert!(!gauntlet.is_over());
+    gauntlet.record(Outcome::default());
+    assert!(gauntlet.is_over());
+    assert!(gauntlet.is_complete());
+    assert!(gauntlet.next_floor().is_none());
+
+    let mut gauntlet = Gauntlet::new(Difficulty::Normal);
+    gauntlet.record(Outcome {
+        failure: Some(String::from("Warrior died!")),
+        ..Outcome::default()
+    });
+    assert!(gauntlet.is_over());
+    assert!(!gauntlet.is_complete());
+    assert_eq!(gauntlet.level(), 1);
+    assert_eq!(gauntlet.floors_climbed(), 0);
+
+    // reaching the stairs without meeting every objective fails the floor too
+    let mut gauntlet = Gauntlet::new(Difficulty::Normal);
+    gauntlet.record(Outcome {
+        objectives: vec![(Objective::RescueAllCaptives, false)],
+        ..Outcome::default()
+    });
+    assert!(gauntlet.is_over());
+    assert_eq!(gauntlet.floors_climbed(), 0);
+}
+
+#[test]
+fn test_play_gauntlet() {
+    let gauntlet = testing::play_gauntlet(|| Box::new(Walker), Difficulty::Normal);
+    assert_eq!(gauntlet.outcomes.len(), 2);
+    assert_eq!(gauntlet.floors_climbed(), 1);
+    assert_eq!(
+        gauntlet.outcomes[1].failure.as_deref(),
+        Some("Warrior died!")
+    );
+    assert_eq!(gauntlet.score(), gauntlet.outcomes[0].score());
+    assert_eq!(gauntlet.top, Floor::count());
+}
@@ -0,0 +1,78 @@
+use rust_warrior::{
+    UnitType,
+    difficulty::Difficulty,
+    floor::Floor,
+    generator::{self, Generator, MAX_HEIGHT, MAX_WIDTH},
+};
+
+#[test]
+fn test_same_seed_same_floor() {
+    let generator = Generator::new(1234, 9).with_difficulty(Difficulty::Hard);
+    assert_eq!(generator.generate().draw(), generator.generate().draw());
+    assert_eq!(generator.generate().difficulty, Difficulty::Hard);
+}
+
+#[test]
+fn test_with_width() {
+    let floor = Generator::new(1, 4).with_width(15).generate();
+    assert_eq!(floor.width, 15);
+    assert_eq!(floor.height, 1);
+
+    let generator = Generator::new(1, 4).with_width(100);
+    assert_eq!(generator.width, MAX_WIDTH);
+}
+
+#[test]
+fn test_with_height() {
+    let floor = Generator::new(3, 8).with_height(3).generate();
+    assert_eq!(floor.height, 3);
+    assert_eq!(floor.draw().lines().count(), 5);
+    // the warrior walks the top row, and only shooters are posted below it
+    assert!(floor.units.iter().all(|unit| {
+        let (_, y) = unit.position;
+        y == 0 || matches!(unit.unit_type, UnitType::Archer | UnitType::Wizard)
+    }));
+    assert!(generator::is_solvable(&floor, 8));
+
+    let generator = Generator::new(1, 4).with_height(100);
+    assert_eq!(generator.height, MAX_HEIGHT);
+}
+
+#[test]
+fn test_palette() {
+    let floor = Generator::new(5, 1).generate();
+    assert_eq!(floor.units.len(), 1);
+    assert!(floor.items.is_empty());
+
+    for seed in 0..5 {
+        let floor = Generator::new(seed, 3).generate();
+        assert!(
+            floor
+                .units
+                .iter()
+                .all(|unit| { matches!(unit.unit_type, UnitType::Warrior | UnitType::Sludge) })
+        );
+        assert!(floor.hazards.is_empty());
+    }
+
+    let generator = Generator::new(5, 42);
+    assert_eq!(generator.level, Floor::count());
+}
+
+#[test]
+fn test_generated_floors_are_solvable() {
+    for level in [2, 5, 7, 9] {
+        for seed in 0..3 {
+            let generator = Generator::new(seed, level).with_difficulty(Difficulty::Hard);
+            let floor = generator.generate();
+            assert!(generator::is_solvable(&floor, level));
+        }
+    }
+}
+
+#[test]
+fn test_is_solvable() {
+    assert!(generator::is_solvable(&Floor::parse("@ s >"), 2));
+    assert!(!generator::is_solvable(&Floor::parse("@ s >"), 1));
+    assert!(!generator::is_solvable(&Floor::parse("@ sss >"), 2));
+}
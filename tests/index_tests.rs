@@ -0,0 +1,42 @@
+mod common;
+
+use std::fs;
+
+use rust_warrior::{
+    index::{INDEX_PATH, ProfileIndex},
+    profile::Profile,
+    starter,
+};
+
+#[test]
+fn test_index() {
+    let dir = common::temp_dir("index-tests");
+    starter::generate_in(Profile::new(String::from("Bob")), &dir).unwrap();
+    starter::generate_in(Profile::new(String::from("Alice")), &dir).unwrap();
+
+    let index = ProfileIndex::load(&dir).unwrap();
+    let names: Vec<&str> = index.warriors.iter().map(|w| w.name.as_str()).collect();
+    assert_eq!(names, vec!["Alice", "Bob"]);
+    assert_eq!(index.current.as_deref(), Some("alice"));
+    assert_eq!(index.find("BOB").unwrap().directory, "bob");
+    assert!(index.find("Carol").is_none());
+
+    let mut source = Profile::new(String::from("Alice"));
+    source.level = 5;
+    starter::generate_clone(&source, "Carol", &dir).unwrap();
+    let carol = Profile::load(&dir.join("carol").join(".profile")).unwrap();
+    assert_eq!(carol.name, "Carol");
+    assert_eq!(carol.level, 5);
+    let readme = fs::read_to_string(dir.join("carol").join("README.md")).unwrap();
+    assert!(readme.starts_with("# Level 5"));
+
+    // directories set up without an index are picked up, and removed ones dropped
+    fs::remove_file(dir.join(INDEX_PATH)).unwrap();
+    fs::remove_dir_all(dir.join("bob")).unwrap();
+    let index = ProfileIndex::load(&dir).unwrap();
+    let names: Vec<&str> = index.warriors.iter().map(|w| w.name.as_str()).collect();
+    assert_eq!(names, vec!["Alice", "Carol"]);
+    assert_eq!(index.current, None);
+
+    fs::remove_dir_all(dir).unwrap();
+}
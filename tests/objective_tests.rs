@@ -0,0 +1,88 @@
+use rust_warrior::{
+    Player, Tile, Warrior, difficulty::Difficulty, engine::outcome::Outcome, objective::Objective,
+    testing,
+};
+
+#[test]
+fn test_objectives_met() {
+    let mut outcome = Outcome {
+        turns: 12,
+        damage_taken: 6,
+        captives_rescued: 2,
+        enemies_defeated: 3,
+        ..Outcome::default()
+    };
+    outcome.evaluate(&[
+        Objective::RescueAllCaptives,
+        Objective::DefeatAllEnemies,
+        Objective::LoseNoCaptives,
+        Objective::WithinTurns(12),
+        Objective::DamageBelow(7),
+    ]);
+
+    assert!(outcome.objectives.iter().all(|(_, met)| *met));
+    assert!(outcome.is_success());
+}
+
+#[test]
+fn test_objectives_failed() {
+    let mut outcome = Outcome {
+        turns: 13,
+        damage_taken: 7,
+        captives_lost: 1,
+        enemies_remaining: 1,
+        ..Outcome::default()
+    };
+    outcome.evaluate(&[
+        Objective::RescueAllCaptives,
+        Objective::DefeatAllEnemies,
+        Objective::LoseNoCaptives,
+        Objective::WithinTurns(12),
+        Objective::DamageBelow(7),
+    ]);
+
+    assert!(outcome.objectives.iter().all(|(_, met)| !*met));
+    assert!(!outcome.is_success());
+}
+
+#[test]
+fn test_objectives_enforced() {
+    // the warrior reaches the stairs of Level 5, but cuts down the captives
+    // on the way
+    let outcome = testing::play_level_at(|| Box::new(Fighter), 5, Difficulty::Normal, 9);
+    assert_eq!(outcome.failure, None);
+    assert_eq!(outcome.captives_lost, 2);
+    assert_eq!(outcome.objectives, vec![(Objective::LoseNoCaptives, false)]);
+    assert!(!outcome.is_success());
+}
+
+#[test]
+fn test_failure_is_not_success() {
+    let outcome = Outcome {
+        failure: Some(String::from("Tester died!")),
+        ..Outcome::default()
+    };
+    assert!(!outcome.is_success());
+}
+
+#[test]
+fn test_objective_display() {
+    assert_eq!(
+        Objective::WithinTurns(20).to_string(),
+        "Reach the stairs within 20 turns"
+    );
+}
+
+// Test Helpers
+
+// walks up to whatever is ahead, and attacks it
+struct Fighter;
+
+impl Player for Fighter {
+    fn play_turn(&mut self, warrior: &Warrior) {
+        match warrior.check() {
+            Tile::Unit(_) => warrior.attack(),
+            _ => warrior.walk(),
+        }
+    }
+}
@@ -0,0 +1,312 @@
+mod common;
+
+use std::{fs, path::Path};
+
+use base64::prelude::*;
+use rust_warrior::{
+    engine::outcome::Outcome,
+    profile::{PROFILE_VERSION, Profile, ProfileError},
+};
+
+#[test]
+fn test_round_trip() {
+    let mut profile = Profile::new(String::from("Bot"));
+    profile.level = 5;
+    let loaded = Profile::from_toml(&profile.to_toml()).unwrap();
+    assert_eq!(loaded.version, PROFILE_VERSION);
+    assert_eq!(loaded.name, "Bot");
+    assert_eq!(loaded.level, 5);
+    assert!(!loaded.maximus_oxidus);
+}
+
+#[test]
+fn test_directory() {
+    let directory = |name: &str| Profile::new(String::from(name)).directory();
+    assert_eq!(directory("Bot"), "bot");
+    assert_eq!(directory("Mr. Bot 2"), "mr-bot-2");
+    assert_eq!(directory("../../etc"), "etc");
+    assert_eq!(directory("/"), "warrior");
+}
+
+#[test]
+fn test_migrate() {
+    // saved before profiles had a version
+    let unversioned = "name = \"Bot\"\nlevel = 9\nmaximus_oxidus = true\n";
+    let profile = Profile::from_toml(&BASE64_STANDARD.encode(unversioned)).unwrap();
+    assert_eq!(profile.version, PROFILE_VERSION);
+    assert!(profile.maximus_oxidus);
+    assert!(profile.daily_history.is_empty());
+
+    // saved before maximus_oxidus, as plain TOML
+    let profile = Profile::from_toml("name = \"Bot\"\nlevel = 3\n").unwrap();
+    assert_eq!(profile.version, PROFILE_VERSION);
+    assert_eq!(profile.level, 3);
+    assert!(!profile.maximus_oxidus);
+
+    // saved before profiles were signed, so no levels can be proven
+    let unsigned = "version = 1\nname = \"Bot\"\nlevel = 3\nmaximus_oxidus = false\n";
+    let profile = Profile::from_toml(&BASE64_STANDARD.encode(unsigned)).unwrap();
+    assert!(profile.completions.is_empty());
+    assert_eq!(profile.unproven_levels(), vec![1, 2]);
+
+    // and whatever they claim must make sense
+    let unsigned = "name = \"Bot\"\nlevel = 9\nmaximus_oxidus = true\n";
+    let profile = Profile::from_toml(unsigned).unwrap();
+    assert_eq!(profile.unproven_levels(), (1..=9).collect::<Vec<usize>>());
+    assert_eq!(profile.proven_level(), 1);
+    for unsigned in [
+        "name = \"Bot\"\nlevel = 10\n",
+        "name = \"Bot\"\nlevel = 0\n",
+        "version = 1\nname = \"Bot\"\nlevel = 3\nmaximus_oxidus = true\n",
+    ] {
+        assert!(matches!(
+            Profile::from_toml(unsigned),
+            Err(ProfileError::Invalid(_))
+        ));
+    }
+}
+
+#[test]
+fn test_migrate_signed() {
+    // signed by each older version, with the default signing key
+    let digest = "b88adc070b740a8256d6dbc398f1d5be56334a146bca973a7105415d9cb1d90e";
+    let v2 = format!(
+        "version = 2\nname = \"Bot\"\nlevel = 2\nmaximus_oxidus = false\ncompletions = [{{ level = 1, score = 26, turns = 6, digest = \"{}\" }}]\n",
+        digest
+    );
+    let v3 = v2.replace("version = 2", "version = 3") + "history = []\n";
+    let v4 = v3.replace("version = 3", "version = 4") + "difficulty = \"Hard\"\n";
+    let v5 = v4.replace("version = 4", "version = 5")
+        + "hardcore = true\nhardcore_history = []\nhardcore_deaths = 1\nhardcore_best = 0\n";
+    let signed = [
+        (
+            v2,
+            "21f2d94a1a8a10af1d9aeba7950c0a155c6873b9a20d497b921f09eba9c46804",
+        ),
+        (
+            v3,
+            "ad4d8a4c71914901f366306c830644d87f75533be4ab6f8a9bda0236b534a870",
+        ),
+        (
+            v4,
+            "93d57a57c2304d8e185a053b6e05fcedacc33f02afa1e6e2a93288dd63509606",
+        ),
+        (
+            v5,
+            "61f0153b2443230ee13f48249e0409a57a649f4dc85d7ec41ec8ca212ecdb3f7",
+        ),
+    ];
+    for (profile_toml, signature) in signed {
+        let contents = format!("{}signature = \"{}\"\n", profile_toml, signature);
+        let profile = Profile::from_toml(&BASE64_STANDARD.encode(&contents)).unwrap();
+        assert_eq!(profile.version, PROFILE_VERSION);
+        assert_eq!(profile.level, 2);
+        assert!(profile.unproven_levels().is_empty());
+
+        let edited = contents.replace("level = 2", "level = 9");
+        assert_eq!(
+            Profile::from_toml(&BASE64_STANDARD.encode(edited)).err(),
+            Some(ProfileError::Tampered)
+        );
+    }
+}
+
+#[test]
+fn test_tampered() {
+    let mut profile = Profile::new(String::from("Bot"));
+    profile.level = 2;
+    let profile_toml =
+        String::from_utf8(BASE64_STANDARD.decode(profile.to_toml()).unwrap()).unwrap();
+    assert!(profile_toml.contains("signature = "));
+
+    let edited = profile_toml.replace("level = 2", "level = 9");
+    assert_eq!(
+        Profile::from_toml(&BASE64_STANDARD.encode(edited)).err(),
+        Some(ProfileError::Tampered)
+    );
+
+    let unsigned: String = profile_toml
+        .lines()
+        .filter(|line| !line.starts_with("signature"))
+        .collect::<Vec<&str>>()
+        .join("\n");
+    assert_eq!(
+        Profile::from_toml(&BASE64_STANDARD.encode(unsigned)).err(),
+        Some(ProfileError::Tampered)
+    );
+}
+
+#[test]
+fn test_completions() {
+    let mut profile = Profile::new(String::from("Bot"));
+    profile.complete_level(1, 40, 10);
+    profile.increment_level();
+    profile.increment_level();
+    assert_eq!(profile.unproven_levels(), vec![2]);
+
+    profile.complete_level(2, 30, 20);
+    // a worse attempt does not replace the best one
+    profile.complete_level(2, 20, 30);
+    let profile = Profile::from_toml(&profile.to_toml()).unwrap();
+    assert!(profile.unproven_levels().is_empty());
+    assert_eq!(profile.completions[1].score, 30);
+    assert!(profile.completions[1].verify("Bot"));
+    assert!(!profile.completions[1].verify("Cheater"));
+
+    let mut forged = profile.completions[1].clone();
+    forged.score = 1000;
+    assert!(!forged.verify("Bot"));
+}
+
+#[test]
+fn test_proven_level() {
+    let mut profile = Profile::new(String::from("Bot"));
+    profile.level = 5;
+    assert_eq!(profile.proven_level(), 1);
+    profile.complete_level(1, 40, 10);
+    profile.complete_level(3, 40, 10);
+    assert_eq!(profile.proven_level(), 2);
+    profile.complete_level(2, 40, 10);
+    assert_eq!(profile.proven_level(), 4);
+
+    // a broken profile only keeps the completions it can still prove
+    let dir = common::temp_dir("recover-profile");
+    let path = dir.join(".profile");
+    let contents = String::from_utf8(BASE64_STANDARD.decode(profile.to_toml()).unwrap()).unwrap();
+    fs::write(&path, contents.replace("level = 5", "level = 9")).unwrap();
+    assert_eq!(Profile::load(&path).err(), Some(ProfileError::Tampered));
+    let recovered = Profile::recover(String::from("Bot"), &path);
+    assert_eq!(recovered.level, 1);
+    assert_eq!(recovered.proven_level(), 4);
+    assert_eq!(
+        Profile::recover(String::from("Cheater"), &path).proven_level(),
+        1
+    );
+}
+
+#[test]
+fn test_record_attempt() {
+    let mut profile = Profile::new(String::from("Bot"));
+    let failure = Outcome {
+        failure: Some(String::from("Bot died!")),
+        ..Outcome::default()
+    };
+    assert!(profile.record_attempt(2, &failure).is_empty());
+    assert_eq!(profile.history[0].attempts, 1);
+    assert_eq!(profile.history[0].first_cleared, None);
+
+    let cleared = Outcome {
+        turns: 30,
+        hp: (12, 20),
+        enemies_defeated: 1,
+        ..Outcome::default()
+    };
+    assert!(profile.record_attempt(2, &cleared).is_empty());
+    let faster = Outcome {
+        turns: 25,
+        hp: (8, 20),
+        ..cleared.clone()
+    };
+    assert_eq!(
+        profile.record_attempt(2, &faster),
+        vec!["score of 42 (was 36)", "25 turns (was 30)"]
+    );
+    profile.record_attempt(1, &cleared);
+
+    let profile = Profile::from_toml(&profile.to_toml()).unwrap();
+    let levels: Vec<usize> = profile.history.iter().map(|h| h.level).collect();
+    assert_eq!(levels, vec![1, 2]);
+    let history = &profile.history[1];
+    assert_eq!(history.attempts, 3);
+    assert_eq!(history.first_cleared.as_ref().map(String::len), Some(10));
+    assert_eq!(history.best_score, Some(42));
+    assert_eq!(history.fewest_turns, Some(25));
+    assert_eq!(history.most_hp, Some(12));
+    assert_eq!(profile.completions[1].score, 42);
+}
+
+#[test]
+fn test_hardcore() {
+    let mut profile = Profile::new(String::from("Bot"));
+    profile.hardcore = true;
+    let cleared = Outcome {
+        turns: 10,
+        ..Outcome::default()
+    };
+    profile.record_attempt(1, &cleared);
+    profile.increment_level();
+    profile.increment_level();
+    profile.record_attempt(2, &cleared);
+    assert!(profile.history.is_empty());
+    assert_eq!(profile.hardcore_history.len(), 2);
+    assert_eq!(profile.hardcore_best, 2);
+
+    let death = Outcome {
+        failure: Some(String::from("Bot died!")),
+        died: true,
+        ..Outcome::default()
+    };
+    profile.record_attempt(3, &death);
+    profile.fall();
+    let profile = Profile::from_toml(&profile.to_toml()).unwrap();
+    assert_eq!(profile.level, 1);
+    assert_eq!(profile.hardcore_deaths, 1);
+    assert_eq!(profile.hardcore_best, 2);
+    assert_eq!(profile.hardcore_history[2].attempts, 1);
+}
+
+#[test]
+fn test_record_daily() {
+    let mut profile = Profile::new(String::from("Bot"));
+    let failure = Outcome {
+        failure: Some(String::from("Bot died!")),
+        ..Outcome::default()
+    };
+    assert!(!profile.record_daily("2024-03-01", &failure));
+    let cleared = Outcome {
+        turns: 30,
+        enemies_defeated: 1,
+        ..Outcome::default()
+    };
+    assert!(profile.record_daily("2024-03-01", &cleared));
+    let slower = Outcome {
+        turns: 40,
+        ..cleared.clone()
+    };
+    assert!(!profile.record_daily("2024-03-01", &slower));
+    assert!(profile.record_daily("2024-03-02", &slower));
+
+    let profile = Profile::from_toml(&profile.to_toml()).unwrap();
+    assert_eq!(profile.daily_history.len(), 2);
+    assert_eq!(profile.daily_history[0].attempts, 3);
+    assert_eq!(profile.daily_history[0].best_score, Some(cleared.score()));
+    assert_eq!(profile.daily_history[1].best_score, Some(slower.score()));
+}
+
+#[test]
+fn test_errors() {
+    let newer = format!(
+        "version = {}\nname = \"Bot\"\nlevel = 3\n",
+        PROFILE_VERSION + 1
+    );
+    assert_eq!(
+        Profile::from_toml(&BASE64_STANDARD.encode(newer)).err(),
+        Some(ProfileError::UnsupportedVersion(PROFILE_VERSION + 1))
+    );
+
+    let nameless = BASE64_STANDARD.encode("version = 1\nlevel = 3\n");
+    assert!(matches!(
+        Profile::from_toml(&nameless),
+        Err(ProfileError::Invalid(_))
+    ));
+    assert!(matches!(
+        Profile::from_toml("not a profile"),
+        Err(ProfileError::Invalid(_))
+    ));
+
+    let path = common::temp_dir("missing-profile").join(".profile");
+    assert_eq!(
+        Profile::load(Path::new(&path)).err(),
+        Some(ProfileError::Missing)
+    );
+}
@@ -0,0 +1,135 @@
+use rust_warrior::{
+    Player, Tile, UnitType, Warrior,
+    engine::{
+        ranged::{self, Ranged},
+        world::World,
+    },
+    floor::Floor,
+    unit::Unit,
+};
+
+#[test]
+fn test_ranged_reaches() {
+    let r = Ranged::new(3, 3);
+    assert!(!r.reaches(0));
+    assert!(r.reaches(1));
+    assert!(r.reaches(3));
+    assert!(!r.reaches(4));
+}
+
+#[test]
+fn test_ranged_damage_falloff() {
+    let r = Ranged::new(3, 3);
+    assert_eq!(r.damage(1), 3);
+    assert_eq!(r.damage(3), 3);
+
+    let r = Ranged {
+        atk: 5,
+        range: 4,
+        falloff: 2,
+    };
+    assert_eq!(r.damage(1), 5);
+    assert_eq!(r.damage(2), 3);
+    assert_eq!(r.damage(4), 0);
+}
+
+#[test]
+fn test_distance() {
+    assert_eq!(ranged::distance((0, 0), (3, 0)), Some(3));
+    assert_eq!(ranged::distance((3, 0), (0, 0)), Some(3));
+    assert_eq!(ranged::distance((1, 1), (1, 4)), Some(3));
+    assert_eq!(ranged::distance((0, 0), (1, 1)), None);
+}
+
+#[test]
+fn test_line_of_sight() {
+    // |@ a w  >|
+    let world = world_with(vec![Unit::archer((2, 0)), Unit::wizard((4, 0))]);
+
+    assert!(ranged::line_of_sight(&world, (0, 0), (2, 0)));
+    assert!(!ranged::line_of_sight(&world, (0, 0), (4, 0)));
+    assert!(ranged::line_of_sight(&world, (4, 0), (7, 0)));
+}
+
+#[test]
+fn test_hit() {
+    // |@  a  w>|
+    let world = world_with(vec![Unit::archer((3, 0)), Unit::wizard((6, 0))]);
+    let archer = Ranged::new(3, 3);
+
+    assert_eq!(ranged::hit(&world, &archer, (3, 0), (0, 0)), Some(3));
+    // out of range
+    assert_eq!(ranged::hit(&world, &archer, (6, 0), (0, 0)), None);
+    // the archer is in the way
+    assert_eq!(ranged::hit(&world, &archer, (6, 0), (2, 0)), None);
+}
+
+#[test]
+fn test_scan() {
+    // |@ a|
+    let mut world = world_with(vec![Unit::archer((2, 0))]);
+    world.floor.width = 3;
+    world.floor.stairs = (2, 0);
+
+    let tiles = ranged::scan(&world, (0, 0), (1, 0), 3);
+    assert_eq!(
+        tiles,
+        vec![
+            ((1, 0), Tile::Empty),
+            ((2, 0), Tile::Unit(UnitType::Archer))
+        ]
+    );
+
+    assert_eq!(ranged::scan(&world, (0, 0), (-1, 0), 3), Vec::new());
+    assert_eq!(
+        ranged::first_target(&world, (0, 0), (1, 0), 3),
+        Some((2, 0))
+    );
+    assert_eq!(ranged::first_target(&world, (0, 0), (1, 0), 1), None);
+}
+
+#[test]
+fn test_doors_block_sight() {
+    // |@ #a   >|
+    let mut world = world_with(vec![Unit::archer((3, 0))]);
+    world.floor.doors.push((2, 0));
+
+    assert!(!ranged::line_of_sight(&world, (3, 0), (0, 0)));
+    assert_eq!(
+        ranged::scan(&world, (0, 0), (1, 0), 3),
+        vec![((1, 0), Tile::Empty), ((2, 0), Tile::Door)]
+    );
+    assert_eq!(ranged::first_target(&world, (0, 0), (1, 0), 3), None);
+
+    world.floor.open_door((2, 0));
+    assert!(ranged::line_of_sight(&world, (3, 0), (0, 0)));
+}
+
+// Test Helpers
+
+struct Idle;
+
+impl Player for Idle {
+    fn play_turn(&mut self, _warrior: &Warrior) {}
+}
+
+fn world_with(other_units: Vec<Unit>) -> World {
+    let warrior = Unit::warrior((0, 0));
+    let mut units = vec![warrior.clone()];
+    units.extend(other_units.clone());
+    let floor = Floor {
+        width: 8,
+        height: 1,
+        stairs: (7, 0),
+        units,
+        ..Floor::default()
+    };
+    World::new(
+        String::from("Tester"),
+        8,
+        floor,
+        Box::new(Idle),
+        warrior,
+        other_units,
+    )
+}
@@ -0,0 +1,81 @@
+mod common;
+
+use std::fs;
+
+use rust_warrior::{difficulty::Difficulty, profile::Profile, starter};
+
+#[test]
+fn test_write_tests() {
+    let dir = common::temp_dir("starter-tests");
+    let mut profile = Profile::new(String::from("Bot"));
+    profile.level = 3;
+
+    starter::write_tests(&profile, Some(&dir)).unwrap();
+    let levels = fs::read_to_string(dir.join("tests").join("levels.rs")).unwrap();
+    assert!(levels.contains("include!(\"../src/main.rs\");"));
+    assert!(levels.contains("use rust_warrior::{difficulty::Difficulty, testing};"));
+    assert!(
+        levels
+            .contains("testing::assert_beats_level_at(Bot::new_player, 1, Difficulty::Normal, 3);")
+    );
+    assert!(!levels.contains("fn level_4()"));
+
+    profile.maximus_oxidus = true;
+    profile.difficulty = Difficulty::Hard;
+    starter::write_tests(&profile, Some(&dir)).unwrap();
+    let levels = fs::read_to_string(dir.join("tests").join("levels.rs")).unwrap();
+    assert!(
+        levels.contains("testing::assert_beats_level_at(Bot::new_player, 9, Difficulty::Hard, 9);")
+    );
+
+    // the struct in src/main.rs cannot have spaces or punctuation in its name
+    let profile = Profile::new(String::from("Mr. Bot 2"));
+    starter::write_tests(&profile, Some(&dir)).unwrap();
+    let levels = fs::read_to_string(dir.join("tests").join("levels.rs")).unwrap();
+    assert!(
+        levels.contains(
+            "testing::assert_beats_level_at(MrBot2::new_player, 1, Difficulty::Normal, 1);"
+        )
+    );
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_upgrade() {
+    let dir = common::temp_dir("upgrade-tests");
+    fs::create_dir_all(dir.join("src")).unwrap();
+    let main_rs = "fn main() {}\n";
+    fs::write(dir.join("src").join("main.rs"), main_rs).unwrap();
+    fs::write(
+        dir.join("Cargo.toml"),
+        "[package]\nname = \"rustwarrior-bot\"\n\n[dependencies]\nrust-warrior = \"0.1.0\" # pinned\n",
+    )
+    .unwrap();
+    // a profile from before maximus_oxidus was saved
+    fs::write(dir.join(".profile"), "name = \"Bot\"\nlevel = 4\n").unwrap();
+
+    let profile = starter::upgrade(Some(&dir)).unwrap();
+    assert_eq!(profile.level, 4);
+    assert!(!profile.maximus_oxidus);
+
+    let cargo_toml = fs::read_to_string(dir.join("Cargo.toml")).unwrap();
+    let dependency = format!(
+        "rust-warrior = \"{}\" # pinned\n",
+        env!("CARGO_PKG_VERSION")
+    );
+    assert!(cargo_toml.starts_with("[package]\nname = \"rustwarrior-bot\"\n"));
+    assert!(cargo_toml.ends_with(&dependency));
+
+    let contents = fs::read_to_string(dir.join(".profile")).unwrap();
+    assert_eq!(Profile::from_toml(&contents).unwrap().name, "Bot");
+    let readme = fs::read_to_string(dir.join("README.md")).unwrap();
+    assert!(readme.starts_with("# Level 4"));
+    assert!(dir.join("tests").join("levels.rs").exists());
+    assert_eq!(
+        fs::read_to_string(dir.join("src").join("main.rs")).unwrap(),
+        main_rs
+    );
+
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,275 @@
+mod common;
+
+use common::Walker;
+use rust_warrior::{
+    Direction, Player, UnitType, Warrior,
+    effect::{Effect, StatusEffect},
+    engine::{
+        systems::{
+            effect_system, golem_system, hazard_system, player_system, shooter_system,
+            sludge_system,
+        },
+        world::World,
+    },
+    floor::Floor,
+    testing,
+    unit::Unit,
+};
+
+#[test]
+fn test_poison_ignores_armor() {
+    // the pool poisons the warrior, whatever armor they wear
+    let damage: Vec<i32> = [1, 4, 9]
+        .iter()
+        .map(|level| {
+            let outcome =
+                testing::run_level_at(|| Box::new(Walker), *level, Floor::parse("@~    >"));
+            let (hp, max) = outcome.hp;
+            max - hp
+        })
+        .collect();
+    assert!(damage[0] > 0);
+    assert_eq!(damage, vec![damage[0]; 3]);
+}
+
+#[test]
+fn test_poison_ticks() {
+    let mut world = world_of("@ s>", Box::new(Walker));
+    world.warriors[0]
+        .unit
+        .afflict(StatusEffect::new(Effect::Poisoned, 2));
+    world.other_units[0].afflict(StatusEffect::new(Effect::Poisoned, 2));
+    world.other_units[0].hp = (1, 12);
+
+    effect_system(&mut world);
+    assert_eq!(world.warriors[0].unit.hp, (19, 20));
+    assert_eq!(world.warriors[0].damage_taken, 1);
+    // the sludge is poisoned to death, and its HP does not go below zero
+    assert!(world.other_units.is_empty());
+    assert_eq!(world.enemies_defeated, 1);
+
+    effect_system(&mut world);
+    assert_eq!(world.warriors[0].unit.hp, (18, 20));
+    // the poison has run its course
+    effect_system(&mut world);
+    assert_eq!(world.warriors[0].unit.hp, (18, 20));
+    assert!(!world.warriors[0].unit.has_effect(Effect::Poisoned));
+}
+
+#[test]
+fn test_stunned_warrior_skips_turn() {
+    let mut world = world_of("@  >", Box::new(Walker));
+    world.warriors[0]
+        .unit
+        .afflict(StatusEffect::new(Effect::Stunned, 1));
+
+    play_turn(&mut world);
+    assert_eq!(world.warriors[0].unit.position, (0, 0));
+    // the stun has worn off
+    play_turn(&mut world);
+    assert_eq!(world.warriors[0].unit.position, (1, 0));
+}
+
+#[test]
+fn test_slowed_warrior_acts_every_other_turn() {
+    let mut world = world_of("@     >", Box::new(Walker));
+    world.warriors[0]
+        .unit
+        .afflict(StatusEffect::new(Effect::Slowed, 3));
+
+    let mut positions = Vec::new();
+    for _ in 0..5 {
+        play_turn(&mut world);
+        let (x, _) = world.warriors[0].unit.position;
+        positions.push(x);
+    }
+    assert_eq!(positions, vec![0, 1, 1, 2, 3]);
+
+    // even a slow of a single turn costs an action
+    let mut world = world_of("@  >", Box::new(Walker));
+    world.warriors[0]
+        .unit
+        .afflict(StatusEffect::new(Effect::Slowed, 1));
+    play_turn(&mut world);
+    assert_eq!(world.warriors[0].unit.position, (0, 0));
+    play_turn(&mut world);
+    assert_eq!(world.warriors[0].unit.position, (1, 0));
+}
+
+#[test]
+fn test_poisoned_warrior_cannot_rest() {
+    let mut world = world_of("@  >", Box::new(Rester));
+    world.warriors[0].unit.hp = (10, 20);
+    world.warriors[0]
+        .unit
+        .afflict(StatusEffect::new(Effect::Poisoned, 1));
+
+    play_turn(&mut world);
+    assert_eq!(world.warriors[0].unit.hp, (9, 20));
+    play_turn(&mut world);
+    play_turn(&mut world);
+    assert!(world.warriors[0].unit.hp.0 > 9);
+}
+
+#[test]
+fn test_wizard_holds_stunning_bolt() {
+    // |@  w>|
+    let mut world = world_of("@  w>", Box::new(Walker));
+    shooter_system(&mut world);
+    let (hp, _) = world.warriors[0].unit.hp;
+    assert!(hp < 20);
+    assert!(world.warriors[0].unit.has_effect(Effect::Stunned));
+    // only the warrior is stunned
+    assert!(!world.other_units[0].has_effect(Effect::Stunned));
+
+    shooter_system(&mut world);
+    assert_eq!(world.warriors[0].unit.hp, (hp, 20));
+}
+
+#[test]
+fn test_spikes() {
+    let mut world = world_of("@^ >", Box::new(Walker));
+    play_turn(&mut world);
+    hazard_system(&mut world);
+    assert_eq!(world.warriors[0].unit.hp, (16, 20));
+    assert!(world.warriors[0].unit.has_effect(Effect::Slowed));
+}
+
+#[test]
+fn test_poison_pool() {
+    let mut world = world_of("@~ >", Box::new(Walker));
+    play_turn(&mut world);
+    hazard_system(&mut world);
+    assert_eq!(world.warriors[0].unit.hp, (20, 20));
+    assert!(world.warriors[0].unit.has_effect(Effect::Poisoned));
+    // the poison takes hold at the start of the next turn
+    effect_system(&mut world);
+    assert_eq!(world.warriors[0].unit.hp, (19, 20));
+}
+
+#[test]
+fn test_pressure_plate() {
+    let mut world = world_of("@_ >", Box::new(Walker));
+    play_turn(&mut world);
+    hazard_system(&mut world);
+    assert_eq!(world.warriors[0].unit.hp, (17, 20));
+    assert!(world.warriors[0].unit.effects.is_empty());
+
+    // the trap only springs when walked onto, not while standing on it
+    world.warriors[0].previous_position = world.warriors[0].unit.position;
+    hazard_system(&mut world);
+    assert_eq!(world.warriors[0].unit.hp, (17, 20));
+}
+
+#[test]
+fn test_golem_walks() {
+    let mut world = world_of("@    >", Box::new(Walker));
+    world.golem = Some(Unit::golem((1, 0)));
+    world.golem_player = Some(Box::new(Walker));
+    golem_system(&mut world);
+    assert_eq!(world.golem.as_ref().unwrap().position, (2, 0));
+
+    // the golem cannot walk through other units
+    world.other_units.push(Unit::sludge((3, 0)));
+    golem_system(&mut world);
+    golem_system(&mut world);
+    assert_eq!(world.golem.as_ref().unwrap().position, (2, 0));
+}
+
+#[test]
+fn test_golem_attacks() {
+    let mut world = world_of("@  s>", Box::new(Walker));
+    world.golem = Some(Unit::golem((2, 0)));
+    world.golem_player = Some(Box::new(Attacker));
+    golem_system(&mut world);
+    assert_eq!(world.other_units[0].hp, (9, 12));
+
+    // and only at half strength behind it
+    world.golem = Some(Unit::golem((4, 0)));
+    world.other_units[0].position = (3, 0);
+    world.golem_player = Some(Box::new(BackAttacker));
+    golem_system(&mut world);
+    assert_eq!(world.other_units[0].hp, (7, 12));
+}
+
+#[test]
+fn test_units_in_other_rows_are_out_of_reach() {
+    // |@  >|
+    // | C  |
+    let mut world = world_of("@  >\n C  ", Box::new(Rescuer));
+    play_turn(&mut world);
+    assert_eq!(world.captives_rescued, 0);
+    assert_eq!(world.other_units.len(), 1);
+
+    // |@  >|
+    // | s  |
+    let mut world = world_of("@  >\n s  ", Box::new(Rescuer));
+    sludge_system(&mut world);
+    assert_eq!(world.warriors[0].unit.hp, (20, 20));
+}
+
+// Test Helpers
+
+struct Attacker;
+
+impl Player for Attacker {
+    fn play_turn(&mut self, warrior: &Warrior) {
+        warrior.attack();
+    }
+}
+
+struct BackAttacker;
+
+impl Player for BackAttacker {
+    fn play_turn(&mut self, warrior: &Warrior) {
+        warrior.attack_toward(Direction::Backward);
+    }
+}
+
+struct Rescuer;
+
+impl Player for Rescuer {
+    fn play_turn(&mut self, warrior: &Warrior) {
+        warrior.rescue();
+    }
+}
+
+struct Rester;
+
+impl Player for Rester {
+    fn play_turn(&mut self, warrior: &Warrior) {
+        warrior.rest();
+    }
+}
+
+// a world for the floor drawn as `snippet`, with every ability unlocked (but
+// no armor)
+fn world_of(snippet: &str, player: Box<dyn Player + Send + Sync>) -> World {
+    let floor = Floor::parse(snippet);
+    let warrior = floor
+        .units
+        .iter()
+        .find(|unit| unit.unit_type == UnitType::Warrior)
+        .cloned()
+        .unwrap();
+    let other_units: Vec<Unit> = floor
+        .units
+        .iter()
+        .filter(|unit| unit.unit_type != UnitType::Warrior)
+        .cloned()
+        .collect();
+    World::new(
+        String::from("Warrior"),
+        Floor::count(),
+        floor,
+        player,
+        warrior,
+        other_units,
+    )
+}
+
+// the warrior's part of a turn: their effects tick, then they act
+fn play_turn(world: &mut World) {
+    effect_system(world);
+    player_system(world);
+}
@@ -0,0 +1,130 @@
+mod common;
+
+use common::Walker;
+use rust_warrior::{
+    Direction, Item, Player, Tile, UnitType, Warrior,
+    actions::Action,
+    difficulty::Difficulty,
+    effect::{Effect, StatusEffect},
+    floor::Floor,
+    testing::{self, Scenario, assert_beats_level},
+    unit::Unit,
+};
+
+#[test]
+fn test_assert_beats_level() {
+    let outcome = assert_beats_level(|| Box::new(Walker), 1);
+    testing::assert_turns_at_most(&outcome, 7);
+    testing::assert_hp_at_least(&outcome, 20);
+    testing::assert_captives_rescued(&outcome, 0);
+}
+
+#[test]
+#[should_panic(expected = "failed to beat Level 2: Warrior died!")]
+fn test_assert_beats_level_fails() {
+    assert_beats_level(|| Box::new(Walker), 2);
+}
+
+#[test]
+#[should_panic(expected = "expected at most 6 turns, but 7 were played")]
+fn test_assert_turns_at_most_fails() {
+    let outcome = testing::play_level(|| Box::new(Walker), 1);
+    testing::assert_turns_at_most(&outcome, 6);
+}
+
+#[test]
+fn test_play_level_died() {
+    let outcome = testing::play_level(|| Box::new(Walker), 2);
+    assert!(outcome.died);
+    assert!(!testing::play_level(|| Box::new(Walker), 1).died);
+}
+
+#[test]
+#[should_panic(expected = "You have not yet learned `check`!")]
+fn test_play_level_learned_abilities() {
+    // `check` is learned on Level 2, and predefined floors are played with
+    // only the abilities learned on reaching them
+    assert_beats_level(|| Box::new(Rescuer), 1);
+}
+
+#[test]
+fn test_play_level_every_ability() {
+    let outcome = testing::play_level_with_every_ability(|| Box::new(Rescuer), 1);
+    assert!(outcome.is_success());
+    let floor = Floor::parse("@C>");
+    let outcome = testing::run_level_with_every_ability(|| Box::new(Rescuer), floor);
+    testing::assert_captives_rescued(&outcome, 1);
+}
+
+#[test]
+#[should_panic(expected = "You have not yet learned `check`!")]
+fn test_play_level_at() {
+    testing::play_level_at(|| Box::new(Rescuer), 1, Difficulty::Normal, 1);
+}
+
+#[test]
+fn test_run_level() {
+    let floor = Floor {
+        width: 4,
+        height: 1,
+        stairs: (3, 0),
+        units: vec![Unit::warrior((0, 0)), Unit::captive((1, 0))],
+        ..Floor::default()
+    };
+    let outcome = testing::run_level_at(|| Box::new(Rescuer), 5, floor);
+    testing::assert_captives_rescued(&outcome, 1);
+    assert!(outcome.is_success());
+
+    // custom floors are played by a warrior on Level 1
+    let outcome = testing::assert_beats_floor(|| Box::new(Walker), Floor::parse("@ >"));
+    assert_eq!(
+        outcome.equipment.map(|equipment| equipment.armor),
+        Some(None)
+    );
+}
+
+#[test]
+fn test_scenario() {
+    let warrior = Scenario::new("S@ a>").warrior();
+    assert_eq!(
+        warrior.look(),
+        &vec![Tile::Empty, Tile::Unit(UnitType::Archer), Tile::Empty]
+    );
+    assert_eq!(
+        warrior.check_toward(Direction::Backward),
+        Tile::Unit(UnitType::ThickSludge)
+    );
+    assert_eq!(warrior.health(), 20);
+
+    let warrior = Scenario::new(" @ ^ s")
+        .with_level(6)
+        .with_health(7)
+        .with_facing(Direction::Backward)
+        .with_inventory(vec![Item::HealthPotion])
+        .with_effects(vec![StatusEffect::new(Effect::Poisoned, 2)])
+        .warrior();
+    assert_eq!(warrior.health(), 7);
+    assert_eq!(warrior.check_toward(Direction::Backward), Tile::Empty);
+    assert_eq!(warrior.inventory(), &vec![Item::HealthPotion]);
+    assert!(warrior.is(Effect::Poisoned));
+}
+
+#[test]
+fn test_scenario_play() {
+    let warrior = Scenario::new("@C>").with_level(5).play(&mut Rescuer);
+    assert_eq!(warrior.action(), Some(Action::Rescue(Direction::Forward)));
+    assert!(warrior.warnings().is_empty());
+}
+
+// Test Helpers
+
+struct Rescuer;
+
+impl Player for Rescuer {
+    fn play_turn(&mut self, warrior: &Warrior) {
+        match warrior.check() {
+            Tile::Unit(_) => warrior.rescue(),
+            _ => warrior.walk(),
+        }
+    }
+}
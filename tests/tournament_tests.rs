@@ -0,0 +1,77 @@
+mod common;
+
+use common::Walker;
+use rust_warrior::{
+    engine::outcome::Outcome,
+    tournament::{self, Entry, Leaderboard},
+};
+
+#[test]
+fn test_outcome_score() {
+    let outcome = Outcome {
+        turns: 40,
+        enemies_defeated: 2,
+        captives_rescued: 1,
+        enemies_remaining: 1,
+        ..Outcome::default()
+    };
+    assert_eq!(outcome.score(), 50);
+
+    let cleared = Outcome {
+        enemies_remaining: 0,
+        ..outcome.clone()
+    };
+    assert_eq!(cleared.score(), 60);
+
+    let failed = Outcome {
+        failure: Some(String::from("Bot died!")),
+        ..outcome
+    };
+    assert_eq!(failed.score(), 0);
+}
+
+#[test]
+fn test_leaderboard_ranking() {
+    let leaderboard = Leaderboard::new(vec![
+        entry("Slow", 2, 100, 30),
+        entry("Low", 2, 80, 10),
+        entry("Fast", 2, 100, 20),
+        entry("Top", 3, 10, 50),
+    ]);
+    let names: Vec<&str> = leaderboard
+        .entries
+        .iter()
+        .map(|entry| entry.name.as_str())
+        .collect();
+    assert_eq!(names, vec!["Top", "Fast", "Slow", "Low"]);
+
+    let markdown = leaderboard.to_markdown();
+    assert!(markdown.starts_with("| Rank | Player | Levels Cleared | Score | Turns | Hardcore |"));
+    assert!(markdown.ends_with("| 4 | Low | 2 | 80 | 10 | 1 |"));
+
+    assert!(leaderboard.to_json().contains("\"name\": \"Top\""));
+}
+
+#[test]
+fn test_tournament_run() {
+    let leaderboard = tournament::run(vec![("Walker", || Box::new(Walker))]);
+    let walker = &leaderboard.entries[0];
+    assert_eq!(walker.results.len(), 9);
+    assert!(walker.results[0].cleared);
+    assert!(!walker.results[1].cleared);
+    assert_eq!(walker.levels_cleared, 1);
+    assert_eq!(walker.hardcore_levels, 1);
+}
+
+// Test Helpers
+
+fn entry(name: &str, levels_cleared: usize, score: i32, turns: usize) -> Entry {
+    Entry {
+        name: String::from(name),
+        levels_cleared,
+        score,
+        turns,
+        hardcore_levels: 1,
+        results: Vec::new(),
+    }
+}
@@ -1,4 +1,9 @@
-use rust_warrior::UnitType;
+use rust_warrior::{
+    UnitType,
+    effect::{Effect, StatusEffect},
+    equipment::{Armor, Bow, Equipment, Weapon},
+    unit::Unit,
+};
 
 #[test]
 fn test_unit_type_draw() {
@@ -8,6 +13,9 @@ fn test_unit_type_draw() {
     let u = UnitType::Captive;
     assert_eq!(u.draw(), "C");
 
+    let u = UnitType::Golem;
+    assert_eq!(u.draw(), "G");
+
     let u = UnitType::Sludge;
     assert_eq!(u.draw(), "s");
 
@@ -20,3 +28,55 @@ fn test_unit_type_draw() {
     let u = UnitType::Wizard;
     assert_eq!(u.draw(), "w");
 }
+
+#[test]
+fn test_warrior_equip() {
+    let mut w = Unit::warrior((0, 0));
+    assert_eq!(w.atk, 5);
+    assert_eq!(w.ranged, None);
+
+    w.equip(Equipment {
+        weapon: Weapon::long_sword(),
+        bow: Some(Bow::short_bow()),
+        armor: None,
+    });
+    assert_eq!(w.atk, 7);
+    assert_eq!(w.ranged, Some(Bow::short_bow().ranged));
+}
+
+#[test]
+fn test_take_damage() {
+    let mut w = Unit::warrior((0, 0));
+    assert_eq!(w.take_damage(3), 3);
+    assert_eq!(w.hp, (17, 20));
+
+    w.equip(Equipment {
+        armor: Some(Armor::chain_mail()),
+        ..Equipment::default()
+    });
+    assert_eq!(w.take_damage(3), 1);
+    assert_eq!(w.hp, (16, 20));
+
+    assert_eq!(w.take_damage(1), 0);
+    assert_eq!(w.take_damage(30), 28);
+    assert_eq!(w.hp, (0, 20));
+}
+
+#[test]
+fn test_afflict() {
+    let mut w = Unit::warrior((0, 0));
+    assert!(!w.has_effect(Effect::Poisoned));
+
+    w.afflict(StatusEffect::new(Effect::Poisoned, 2));
+    assert!(w.has_effect(Effect::Poisoned));
+
+    // the longer duration wins
+    w.afflict(StatusEffect::new(Effect::Poisoned, 1));
+    assert_eq!(w.effects, vec![StatusEffect::new(Effect::Poisoned, 2)]);
+    w.afflict(StatusEffect::new(Effect::Poisoned, 3));
+    assert_eq!(w.effects, vec![StatusEffect::new(Effect::Poisoned, 3)]);
+
+    w.afflict(StatusEffect::new(Effect::Stunned, 1));
+    assert!(w.has_effect(Effect::Stunned));
+    assert!(!w.has_effect(Effect::Slowed));
+}
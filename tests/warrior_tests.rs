@@ -1,4 +1,9 @@
-use rust_warrior::{actions::Action, Direction, Tile, UnitType, Warrior};
+use rust_warrior::{
+    Direction, Item, Tile, UnitType, Warrior,
+    actions::Action,
+    effect::{Effect, StatusEffect},
+    equipment::{Armor, Bow, Equipment, LONG_BOW_AMMO, Weapon},
+};
 
 // Actions
 
@@ -79,6 +84,14 @@ fn test_shoot_not_unlocked() {
     warrior.shoot();
 }
 
+#[test]
+fn test_use_item() {
+    let warrior = warrior_at_level(1).with_inventory(vec![Item::HealthPotion]);
+    assert_eq!(warrior.inventory(), &vec![Item::HealthPotion]);
+    warrior.use_item(Item::HealthPotion);
+    assert_eq!(warrior.action(), Some(Action::Use(Item::HealthPotion)));
+}
+
 // Backward Actions
 
 #[test]
@@ -200,6 +213,46 @@ fn test_health_not_unlocked() {
     warrior.health();
 }
 
+#[test]
+fn test_equipment() {
+    let warrior = warrior_at_level(1);
+    assert_eq!(warrior.equipment(), &Equipment::default());
+    assert_eq!(warrior.equipment().bow, None);
+
+    let warrior = warrior_at_level(4);
+    assert_eq!(warrior.equipment().armor, Some(Armor::leather()));
+
+    let warrior = warrior_at_level(6);
+    assert_eq!(warrior.equipment().weapon, Weapon::long_sword());
+
+    let warrior = warrior_at_level(8);
+    assert_eq!(warrior.equipment().bow, Some(Bow::short_bow()));
+
+    let warrior = warrior_at_level(9);
+    assert_eq!(warrior.equipment().armor, Some(Armor::chain_mail()));
+    assert_eq!(warrior.equipment().bow, Some(Bow::long_bow(LONG_BOW_AMMO)));
+
+    let equipment = Equipment {
+        bow: Some(Bow::long_bow(5)),
+        ..Equipment::default()
+    };
+    let warrior = warrior_at_level(8).with_equipment(equipment);
+    assert_eq!(warrior.equipment().bow.unwrap().ammo, Some(5));
+}
+
+#[test]
+fn test_effects() {
+    let warrior = warrior_at_level(1);
+    assert!(warrior.effects().is_empty());
+    assert!(!warrior.is(Effect::Poisoned));
+
+    let poisoned = StatusEffect::new(Effect::Poisoned, 1);
+    let warrior = warrior_at_level(1).with_effects(vec![poisoned]);
+    assert_eq!(warrior.effects(), &vec![poisoned]);
+    assert!(warrior.is(Effect::Poisoned));
+    assert!(!warrior.is(Effect::Stunned));
+}
+
 // Backward Non-Action Methods
 
 #[test]
@@ -236,8 +289,36 @@ fn test_look_backward_not_unlocked() {
     warrior.look_toward(Direction::Backward);
 }
 
+// Golem
+
+#[test]
+fn test_golem() {
+    let golem = golem();
+    assert!(golem.is_golem());
+    assert!(!warrior_at_level(7).is_golem());
+
+    golem.attack_toward(Direction::Backward);
+    assert_eq!(golem.action(), Some(Action::Attack(Direction::Backward)));
+}
+
+#[test]
+#[should_panic(expected = "A golem cannot `rest`!")]
+fn test_golem_cannot_rest() {
+    golem().rest();
+}
+
+#[test]
+#[should_panic(expected = "A golem cannot `rescue`!")]
+fn test_golem_cannot_rescue() {
+    golem().rescue();
+}
+
 // Test Helpers
 
+fn golem() -> Warrior {
+    Warrior::golem(Vec::new(), Vec::new(), 10, Direction::Forward)
+}
+
 fn warrior_at_level(level: usize) -> Warrior {
     Warrior::new(level, Vec::new(), Vec::new(), 0, Direction::Forward)
 }